@@ -0,0 +1,20 @@
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    #[cfg(feature = "capi")]
+    {
+        let crate_dir = std::path::PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap());
+        let config = cbindgen::Config::from_file(crate_dir.join("cbindgen.toml")).expect("Unable to read cbindgen.toml.");
+
+        // ONLY THE C API GOES INTO THE HEADER, NOT THE REST OF THE CRATE
+        cbindgen::Builder::new()
+            .with_config(config)
+            .with_src(crate_dir.join("src").join("capi.rs"))
+            .generate()
+            .expect("Unable to generate C bindings.")
+            .write_to_file(crate_dir.join("include").join("chess_engine.h"));
+
+        println!("cargo:rerun-if-changed=src/capi.rs");
+        println!("cargo:rerun-if-changed=cbindgen.toml");
+    }
+}
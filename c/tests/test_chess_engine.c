@@ -0,0 +1,73 @@
+#include <assert.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "chess_engine.h"
+
+static void test_start_position(void) {
+    ChessBoard *board = chess_board_new();
+    ChessMove moves[32];
+    size_t count = 0;
+    uint8_t pieces[64];
+
+    assert(board != NULL);
+    assert(chess_board_active_color(board) == CHESS_WHITE_TO_MOVE);
+
+    /* g1 KNIGHT */
+    assert(chess_board_legal_moves(board, 6, moves, 32, &count) == CHESS_OK);
+    assert(count == 2);
+    assert(moves[0].from == 6 && moves[1].from == 6);
+
+    assert(chess_board_get_pieces(board, pieces) == CHESS_OK);
+    assert(pieces[4] == CHESS_KING);
+    assert(pieces[60] == (CHESS_KING | CHESS_BLACK));
+    assert(pieces[35] == CHESS_EMPTY);
+
+    chess_board_free(board);
+}
+
+static void test_moves(void) {
+    ChessBoard *board = chess_board_new();
+    ChessMove e4 = { 12, 28, CHESS_EMPTY };
+    char fen[128];
+
+    assert(chess_board_make_move(board, e4) == CHESS_OK);
+    assert(chess_board_make_move(board, e4) == CHESS_ERR_NO_PIECE);
+    assert(chess_board_active_color(board) == CHESS_BLACK_TO_MOVE);
+
+    assert(chess_board_get_fen(board, fen, sizeof(fen)) == CHESS_OK);
+    assert(strcmp(fen, "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1") == 0);
+    assert(chess_board_get_fen(board, fen, 8) == CHESS_ERR_BUFFER_TOO_SMALL);
+
+    chess_board_free(board);
+}
+
+static void test_errors(void) {
+    ChessBoard *board = chess_board_new();
+    ChessMove moves[1];
+    size_t count = 0;
+
+    assert(chess_board_set_fen(board, "not a position") == CHESS_ERR_INVALID_POSITION);
+    assert(chess_board_set_fen(board, "4k3/P7/8/8/8/8/8/4K3 w - - 0 1") == CHESS_OK);
+
+    /* a7 PAWN PROMOTES FOUR WAYS */
+    assert(chess_board_legal_moves(board, 48, moves, 1, &count) == CHESS_ERR_BUFFER_TOO_SMALL);
+    assert(count == 4);
+    assert(chess_board_legal_moves(board, 64, moves, 1, &count) == CHESS_ERR_INVALID_ARGUMENT);
+
+    assert(chess_board_set_fen(NULL, "") == CHESS_ERR_NULL_POINTER);
+    assert(chess_board_active_color(NULL) == CHESS_ERR_NULL_POINTER);
+
+    chess_board_free(board);
+    chess_board_free(NULL);
+}
+
+int main(void) {
+    test_start_position();
+    test_moves();
+    test_errors();
+
+    printf("ok\n");
+
+    return 0;
+}
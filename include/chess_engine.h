@@ -0,0 +1,131 @@
+#ifndef CHESS_ENGINE_H
+#define CHESS_ENGINE_H
+
+/* Generated by cbindgen from src/capi.rs, do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define CHESS_OK 0
+
+#define CHESS_ERR_NULL_POINTER -1
+
+#define CHESS_ERR_INVALID_ARGUMENT -2
+
+#define CHESS_ERR_INVALID_POSITION -3
+
+#define CHESS_ERR_NO_PIECE -4
+
+#define CHESS_ERR_NOT_YOUR_TURN -5
+
+#define CHESS_ERR_ILLEGAL_MOVE -6
+
+#define CHESS_ERR_BUFFER_TOO_SMALL -7
+
+#define CHESS_ERR_INTERNAL -8
+
+#define CHESS_EMPTY 0
+
+#define CHESS_PAWN 1
+
+#define CHESS_KNIGHT 2
+
+#define CHESS_BISHOP 3
+
+#define CHESS_ROOK 4
+
+#define CHESS_QUEEN 5
+
+#define CHESS_KING 6
+
+#define CHESS_BLACK 8
+
+#define CHESS_WHITE_TO_MOVE 0
+
+#define CHESS_BLACK_TO_MOVE 1
+
+typedef struct ChessBoard ChessBoard;
+
+typedef struct ChessMove {
+  uint8_t from;
+  uint8_t to;
+  uint8_t promotion;
+} ChessMove;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Creates a board in the starting position, free it with chess_board_free.
+ */
+struct ChessBoard *chess_board_new(void);
+
+/**
+ * # Safety
+ * `board` must come from chess_board_new and not be used afterwards. Null is ignored.
+ */
+void chess_board_free(struct ChessBoard *board);
+
+/**
+ * Replaces the position with a FEN string, the board is left as it was on errors.
+ *
+ * # Safety
+ * `board` must be a live board and `fen` a null terminated string.
+ */
+int32_t chess_board_set_fen(struct ChessBoard *board, const char *fen);
+
+/**
+ * Writes the position as a null terminated FEN string into `buffer`.
+ *
+ * # Safety
+ * `board` must be a live board and `buffer` must hold `length` bytes.
+ */
+int32_t chess_board_get_fen(const struct ChessBoard *board, char *buffer, size_t length);
+
+/**
+ * Writes the legal moves of the piece on `square` into `moves` and their number into `count`.
+ * A square without a piece of the side to move has no moves. When `capacity` is too small,
+ * `count` still receives the number of moves needed.
+ *
+ * # Safety
+ * `board` must be a live board, `moves` must hold `capacity` moves and `count` must be writable.
+ */
+int32_t chess_board_legal_moves(const struct ChessBoard *board,
+                                uint8_t square,
+                                struct ChessMove *moves,
+                                size_t capacity,
+                                size_t *count);
+
+/**
+ * Plays a move for the side to move. Promotions without a promotion role use the promotion setting.
+ *
+ * # Safety
+ * `board` must be a live board.
+ */
+int32_t chess_board_make_move(struct ChessBoard *board, struct ChessMove chess_move);
+
+/**
+ * Writes 64 piece codes, one per square index, into `pieces`.
+ *
+ * # Safety
+ * `board` must be a live board and `pieces` must hold 64 bytes.
+ */
+int32_t chess_board_get_pieces(const struct ChessBoard *board, uint8_t *pieces);
+
+/**
+ * Returns CHESS_WHITE_TO_MOVE or CHESS_BLACK_TO_MOVE, or an error code for a null board.
+ *
+ * # Safety
+ * `board` must be a live board or null.
+ */
+int32_t chess_board_active_color(const struct ChessBoard *board);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* CHESS_ENGINE_H */
@@ -0,0 +1,116 @@
+import pytest
+
+from chess_engine import Board, ChessPiece, ChessRole, ChessSquare, Color, Move
+
+STARTING_POSITION = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"
+
+
+def test_square():
+    square = ChessSquare(4, 3)
+
+    assert square.file == 4
+    assert square.rank == 3
+    assert str(square) == "e4"
+    assert repr(square) == "ChessSquare(4, 3)"
+    assert ChessSquare.parse("e4") == square
+    assert hash(ChessSquare.parse("e4")) == hash(square)
+    assert len({ChessSquare(0, 0), ChessSquare(0, 0), ChessSquare(7, 7)}) == 2
+
+    with pytest.raises(ValueError):
+        ChessSquare(8, 0)
+
+    with pytest.raises(ValueError):
+        ChessSquare.parse("i9")
+
+
+def test_piece():
+    piece = ChessPiece(Color.White, ChessRole.Knight)
+
+    assert piece.color == Color.White
+    assert piece.role == ChessRole.Knight
+    assert piece == ChessPiece(Color.White, ChessRole.Knight)
+    assert piece != ChessPiece(Color.Black, ChessRole.Knight)
+    assert repr(piece) == "ChessPiece(Color.White, ChessRole.Knight)"
+    assert {piece: 1}[ChessPiece(Color.White, ChessRole.Knight)] == 1
+
+
+def test_move():
+    chess_move = Move(ChessSquare(4, 6), ChessSquare(4, 7), ChessRole.Queen)
+
+    assert chess_move.from_square == ChessSquare(4, 6)
+    assert chess_move.to_square == ChessSquare(4, 7)
+    assert chess_move.promotion == ChessRole.Queen
+    assert str(chess_move) == "e7e8q"
+    assert repr(chess_move) == "Move(ChessSquare(4, 6), ChessSquare(4, 7), ChessRole.Queen)"
+    assert repr(Move(ChessSquare(4, 1), ChessSquare(4, 3))) == "Move(ChessSquare(4, 1), ChessSquare(4, 3))"
+
+    with pytest.raises(ValueError):
+        Move(ChessSquare(4, 6), ChessSquare(4, 7), ChessRole.King)
+
+
+def test_board():
+    board = Board()
+
+    assert board.fen() == STARTING_POSITION
+    assert board == Board(STARTING_POSITION)
+    assert repr(board) == "Board('{}')".format(STARTING_POSITION)
+    assert board.turn == Color.White
+    assert board.piece_at(ChessSquare(4, 0)) == ChessPiece(Color.White, ChessRole.King)
+    assert board.piece_at(ChessSquare(4, 3)) is None
+    assert board.perft(2) == 400
+
+    with pytest.raises(ValueError):
+        Board("8/8/8/8/8/8/8/4K3 w - - 0 1")
+
+
+def test_legal_moves():
+    moves = Board().legal_moves()
+
+    assert len(moves) == 20
+    assert Move(ChessSquare(6, 0), ChessSquare(5, 2)) in moves
+    assert all(chess_move.piece.color == Color.White for chess_move in moves)
+
+    board = Board("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1")
+    en_passent = [chess_move for chess_move in board.legal_moves() if chess_move.is_en_passent()]
+
+    assert len(en_passent) == 1
+    assert en_passent[0].is_capture()
+    assert en_passent[0].captured == ChessRole.Pawn
+
+
+def test_make_moves():
+    board = Board()
+
+    assert board.push(Move(ChessSquare(4, 1), ChessSquare(4, 3))) == "e4"
+    assert board.push_san("e5") == Move(ChessSquare(4, 6), ChessSquare(4, 4))
+    assert board.push_uci("g1f3") == Move(ChessSquare(6, 0), ChessSquare(5, 2))
+    assert board.turn == Color.Black
+    assert board.fen() == "rnbqkbnr/pppp1ppp/8/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R b KQkq - 1 2"
+
+    copy = board.copy()
+    copy.push_san("Nc6")
+
+    assert copy != board
+
+    with pytest.raises(ValueError):
+        board.push(Move(ChessSquare(4, 4), ChessSquare(4, 3)))
+
+    with pytest.raises(ValueError):
+        board.push_san("Qxf7")
+
+
+def test_status():
+    board = Board()
+
+    for san in ["f3", "e5", "g4"]:
+        board.push_san(san)
+
+    assert board.san(Move(ChessSquare(3, 7), ChessSquare(7, 3))) == "Qh4#"
+
+    board.push_san("Qh4#")
+
+    assert board.is_check()
+    assert board.is_checkmate()
+    assert board.is_game_over()
+    assert board.status() == "Checkmate(Black)"
+    assert board.legal_moves() == []
@@ -0,0 +1,75 @@
+use std::env;
+use std::process;
+use std::time::Instant;
+
+use engine::board::Board;
+use engine::fen::STARTING_POSITION;
+
+const USAGE: &str = "usage: perft <depth> [fen]";
+
+fn parse_args(args: &[String]) -> Result<(u32, Board), String> {
+    let depth = match args.first() {
+        Some(depth) => depth.parse::<u32>().map_err(|_| format!("invalid depth: {}", depth))?,
+        None => return Err(USAGE.to_string()),
+    };
+
+    // THE FEN MAY BE PASSED AS ONE ARGUMENT OR SPLIT ON WHITESPACE
+    let fen = if args.len() > 1 { args[1..].join(" ") } else { STARTING_POSITION.to_string() };
+    let board = Board::from_fen(&fen).map_err(|err| format!("invalid fen: {}", err))?;
+
+    Ok((depth, board))
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let (depth, board) = match parse_args(&args) {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(1);
+        },
+    };
+
+    let start = Instant::now();
+    let mut total = 0;
+
+    for (chess_move, nodes) in board.perft_divide(depth) {
+        println!("{}: {}", chess_move.to_uci(), nodes);
+
+        total += nodes;
+    }
+
+    // DEPTH 0 HAS NO MOVES TO DIVIDE BY BUT STILL COUNTS THE ROOT
+    if depth == 0 {
+        total = board.perft(0);
+    }
+
+    let millis = start.elapsed().as_millis();
+
+    println!();
+    println!("Nodes searched: {}", total);
+    println!("Time: {} ms ({} nps)", millis, total as u128 * 1000 / millis.max(1));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse_args;
+
+    #[test]
+    fn args() {
+        let args: Vec<String> = ["3", "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8", "w", "-", "-", "0", "1"].iter().map(|arg| arg.to_string()).collect();
+        let (depth, board) = parse_args(&args).unwrap();
+
+        assert_eq!(depth, 3);
+        assert_eq!(board.to_fen(), "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1");
+
+        let (_, board) = parse_args(&["1".to_string()]).unwrap();
+
+        assert_eq!(board.perft(1), 20);
+
+        assert!(parse_args(&[]).is_err());
+        assert!(parse_args(&["x".to_string()]).is_err());
+        assert!(parse_args(&["1".to_string(), "invalid".to_string()]).is_err());
+    }
+}
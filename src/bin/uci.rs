@@ -0,0 +1,313 @@
+use std::io;
+use std::io::BufRead;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::thread;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use engine::board::Board;
+use engine::board::ChessError;
+use engine::board::ChessRole;
+use engine::board::ChessSquare;
+use engine::board::Move;
+use engine::board::MoveOutcome;
+use engine::color::Color;
+use engine::search::SearchLimits;
+use engine::search::Searcher;
+
+const NAME: &str = "Viola";
+const AUTHOR: &str = "violaso";
+
+const DEFAULT_MOVE_OVERHEAD: u64 = 30;
+const DEFAULT_MOVES_TO_GO: u64 = 30;
+
+type UciMove = (ChessSquare, ChessSquare, Option<ChessRole>);
+
+#[derive(Default)]
+struct GoParams {
+    depth: Option<u32>,
+    nodes: Option<u64>,
+    movetime: Option<u64>,
+    wtime: Option<u64>,
+    btime: Option<u64>,
+    winc: Option<u64>,
+    binc: Option<u64>,
+    movestogo: Option<u64>,
+    infinite: bool,
+}
+
+impl GoParams {
+    fn parse(tokens: &[&str]) -> GoParams {
+        let mut params = GoParams::default();
+
+        let mut i = 0;
+        while i < tokens.len() {
+            let value = tokens.get(i + 1).and_then(|value| value.parse::<u64>().ok());
+
+            match tokens[i] {
+                "depth" => params.depth = value.map(|depth| depth as u32),
+                "nodes" => params.nodes = value,
+                "movetime" => params.movetime = value,
+                "wtime" => params.wtime = value,
+                "btime" => params.btime = value,
+                "winc" => params.winc = value,
+                "binc" => params.binc = value,
+                "movestogo" => params.movestogo = value,
+                "infinite" => {
+                    params.infinite = true;
+                    i += 1;
+                    continue;
+                },
+                _ => {
+                    i += 1;
+                    continue;
+                },
+            }
+
+            i += 2;
+        }
+
+        params
+    }
+
+    fn time_budget(&self, color: Color, move_overhead: u64) -> Option<Duration> {
+        if self.infinite {
+            return None;
+        }
+
+        if let Some(movetime) = self.movetime {
+            return Some(Duration::from_millis(movetime.saturating_sub(move_overhead).max(1)));
+        }
+
+        let (time, increment) = match color {
+            Color::White => (self.wtime, self.winc.unwrap_or(0)),
+            Color::Black => (self.btime, self.binc.unwrap_or(0)),
+        };
+
+        time.map(|time| {
+            let moves_to_go = self.movestogo.unwrap_or(DEFAULT_MOVES_TO_GO).max(1);
+            let budget = time / moves_to_go + increment * 3 / 4;
+
+            Duration::from_millis(budget.min(time.saturating_sub(move_overhead)).max(1))
+        })
+    }
+}
+
+fn play(board: &mut Board, uci_move: UciMove) -> Result<MoveOutcome, ChessError> {
+    let (from, to, promotion) = uci_move;
+
+    board.play(Move::try_new(from, to, promotion)?)
+}
+
+fn parse_position(tokens: &[&str]) -> Result<Board, String> {
+    let moves_index = tokens.iter().position(|&token| token == "moves").unwrap_or(tokens.len());
+
+    let mut board = match tokens.first() {
+        Some(&"startpos") => Board::new(),
+        Some(&"fen") => Board::from_fen(&tokens[1..moves_index].join(" ")).map_err(|err| format!("{}", err))?,
+        _ => return Err("position must be given as startpos or fen.".to_string()),
+    };
+
+    for uci in tokens.iter().skip(moves_index + 1) {
+        let uci_move = board.parse_uci(uci).map_err(|err| format!("{}", err))?;
+
+        play(&mut board, uci_move).map_err(|err| format!("{}", err))?;
+    }
+
+    Ok(board)
+}
+
+// ---
+
+struct Search {
+    stop: Arc<AtomicBool>,
+    handle: JoinHandle<()>,
+}
+
+fn pv_to_uci(pv: &[Move]) -> String {
+    pv.iter().map(|chess_move| chess_move.to_uci()).collect::<Vec<String>>().join(" ")
+}
+
+fn search(board: Board, mut searcher: Searcher, infinite: bool) {
+    let stop = searcher.get_stop_flag();
+
+    let result = searcher.search_with(&board, |result| {
+        let millis = result.get_time().as_millis();
+        let nps = result.get_nodes() as u128 * 1000 / millis.max(1);
+
+        println!("info depth {} score {} nodes {} nps {} time {} pv {}", result.get_depth(), result.get_score(), result.get_nodes(), nps, millis, pv_to_uci(result.get_pv()));
+    });
+
+    let bestmove = result.get_best_move()
+        .map(|chess_move| chess_move.to_uci())
+        .unwrap_or_else(|| "0000".to_string());
+
+    // IN INFINITE MODE THE BEST MOVE IS ONLY REPORTED AFTER STOP
+    while infinite && !stop.load(Ordering::Relaxed) {
+        thread::sleep(Duration::from_millis(1));
+    }
+
+    println!("bestmove {}", bestmove);
+}
+
+struct Uci {
+    board: Board,
+    move_overhead: u64,
+    search: Option<Search>,
+}
+
+impl Uci {
+    fn new() -> Uci {
+        Uci {
+            board: Board::new(),
+            move_overhead: DEFAULT_MOVE_OVERHEAD,
+            search: None,
+        }
+    }
+
+    fn stop(&mut self) {
+        if let Some(search) = self.search.take() {
+            search.stop.store(true, Ordering::Relaxed);
+
+            let _ = search.handle.join();
+        }
+    }
+
+    fn go(&mut self, tokens: &[&str]) {
+        self.stop();
+
+        let params = GoParams::parse(tokens);
+        let limits = SearchLimits {
+            depth: params.depth,
+            nodes: params.nodes,
+            time: params.time_budget(self.board.get_active_color(), self.move_overhead),
+        };
+
+        let board = self.board.clone();
+        let searcher = Searcher::new(limits);
+        let stop = searcher.get_stop_flag();
+
+        let handle = thread::spawn(move || search(board, searcher, params.infinite));
+
+        self.search = Some(Search { stop, handle });
+    }
+
+    fn set_option(&mut self, tokens: &[&str]) {
+        let name_index = tokens.iter().position(|&token| token == "name").map(|i| i + 1).unwrap_or(tokens.len());
+        let value_index = tokens.iter().position(|&token| token == "value").unwrap_or(tokens.len());
+
+        let name = tokens[name_index.min(value_index)..value_index].join(" ");
+        let value = tokens.get(value_index + 1..).map(|value| value.join(" ")).unwrap_or_default();
+
+        match name.to_lowercase().as_str() {
+            "move overhead" => match value.parse::<u64>() {
+                Ok(overhead) => self.move_overhead = overhead,
+                Err(_) => println!("info string invalid value for {}: {}", name, value),
+            },
+            _ => println!("info string unknown option {}", name),
+        }
+    }
+
+    fn handle(&mut self, line: &str) -> bool {
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+
+        let (command, args) = match tokens.split_first() {
+            Some((command, args)) => (*command, args),
+            None => return true,
+        };
+
+        match command {
+            "uci" => {
+                println!("id name {}", NAME);
+                println!("id author {}", AUTHOR);
+                println!("option name Move Overhead type spin default {} min 0 max 5000", DEFAULT_MOVE_OVERHEAD);
+                println!("uciok");
+            },
+            "isready" => println!("readyok"),
+            "ucinewgame" => {
+                self.stop();
+                self.board = Board::new();
+            },
+            "position" => {
+                self.stop();
+
+                match parse_position(args) {
+                    Ok(board) => self.board = board,
+                    Err(err) => println!("info string {}", err),
+                }
+            },
+            "go" => self.go(args),
+            "stop" => self.stop(),
+            "setoption" => self.set_option(args),
+            "quit" => {
+                self.stop();
+                return false;
+            },
+            _ => println!("info string unknown command {}", command),
+        }
+
+        true
+    }
+}
+
+fn main() {
+    let mut uci = Uci::new();
+
+    for line in io::stdin().lock().lines() {
+        let line = match line {
+            Ok(line) => line,
+            Err(_) => break,
+        };
+
+        if !uci.handle(&line) {
+            return;
+        }
+    }
+
+    uci.stop();
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use engine::color::Color;
+
+    use crate::GoParams;
+    use crate::parse_position;
+
+    #[test]
+    fn go_params() {
+        let params = GoParams::parse(&["wtime", "60000", "btime", "30000", "winc", "1000", "binc", "0", "depth", "6", "nodes", "5000"]);
+
+        assert_eq!(params.wtime, Some(60000));
+        assert_eq!(params.btime, Some(30000));
+        assert_eq!(params.depth, Some(6));
+        assert_eq!(params.nodes, Some(5000));
+        assert!(!params.infinite);
+
+        assert_eq!(params.time_budget(Color::White, 0), Some(Duration::from_millis(60000 / 30 + 750)));
+        assert_eq!(params.time_budget(Color::Black, 0), Some(Duration::from_millis(30000 / 30)));
+
+        assert_eq!(GoParams::parse(&["movetime", "500"]).time_budget(Color::White, 30), Some(Duration::from_millis(470)));
+        assert_eq!(GoParams::parse(&["infinite"]).time_budget(Color::White, 30), None);
+        assert_eq!(GoParams::parse(&["depth", "3"]).time_budget(Color::White, 30), None);
+    }
+
+    #[test]
+    fn position() {
+        let board = parse_position(&["startpos", "moves", "e2e4", "e7e5", "g1f3"]).unwrap();
+
+        assert_eq!(board.to_fen(), "rnbqkbnr/pppp1ppp/8/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R b KQkq - 1 2");
+
+        let board = parse_position(&["fen", "4k3/P7/8/8/8/8/8/4K3", "w", "-", "-", "0", "1", "moves", "a7a8n"]).unwrap();
+
+        assert_eq!(board.to_fen(), "N3k3/8/8/8/8/8/8/4K3 b - - 0 1");
+
+        assert!(parse_position(&["startpos", "moves", "e2e5"]).is_err());
+        assert!(parse_position(&["fen", "invalid"]).is_err());
+        assert!(parse_position(&[]).is_err());
+    }
+}
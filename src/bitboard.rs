@@ -0,0 +1,545 @@
+use std::fmt;
+use std::sync::OnceLock;
+
+use crate::color::Color;
+use crate::comp::CastlingRights;
+use crate::role::Role;
+use crate::zobrist;
+
+/*
+ * Bitboards hold one bit per square, square index = rank * 8 + file.
+ */
+
+pub const ROLES: [Role; 6] = [Role::King, Role::Queen, Role::Bichop, Role::Knight, Role::Rook, Role::Pawn];
+
+const KNIGHT_STEPS: [(i8, i8); 8] = [(1, 2), (2, 1), (2, -1), (1, -2), (-1, -2), (-2, -1), (-2, 1), (-1, 2)];
+const KING_STEPS: [(i8, i8); 8] = [(0, 1), (1, 1), (1, 0), (1, -1), (0, -1), (-1, -1), (-1, 0), (-1, 1)];
+const WHITE_PAWN_STEPS: [(i8, i8); 2] = [(-1, 1), (1, 1)];
+const BLACK_PAWN_STEPS: [(i8, i8); 2] = [(-1, -1), (1, -1)];
+
+// THE FIRST FOUR DIRECTIONS RUN TOWARDS HIGHER SQUARE INDICES
+const NORTH: usize = 0;
+const NORTH_EAST: usize = 1;
+const EAST: usize = 2;
+const NORTH_WEST: usize = 3;
+const SOUTH: usize = 4;
+const SOUTH_WEST: usize = 5;
+const WEST: usize = 6;
+const SOUTH_EAST: usize = 7;
+
+const BICHOP_DIRECTIONS: [usize; 4] = [NORTH_EAST, NORTH_WEST, SOUTH_WEST, SOUTH_EAST];
+const ROOK_DIRECTIONS: [usize; 4] = [NORTH, EAST, SOUTH, WEST];
+
+const DIRECTIONS: [(i8, i8); 8] = [(0, 1), (1, 1), (1, 0), (-1, 1), (0, -1), (-1, -1), (-1, 0), (1, -1)];
+
+const fn step_attacks(steps: &[(i8, i8)]) -> [u64; 64] {
+    let mut table = [0; 64];
+
+    let mut square = 0;
+    while square < 64 {
+        let mut i = 0;
+        while i < steps.len() {
+            let file = (square % 8) as i8 + steps[i].0;
+            let rank = (square / 8) as i8 + steps[i].1;
+
+            if file >= 0 && file < 8 && rank >= 0 && rank < 8 {
+                table[square] |= 1 << (rank * 8 + file);
+            }
+
+            i += 1;
+        }
+
+        square += 1;
+    }
+
+    table
+}
+
+const fn rays() -> [[u64; 64]; 8] {
+    let mut table = [[0; 64]; 8];
+
+    let mut direction = 0;
+    while direction < 8 {
+        let mut square = 0;
+        while square < 64 {
+            let mut file = (square % 8) as i8 + DIRECTIONS[direction].0;
+            let mut rank = (square / 8) as i8 + DIRECTIONS[direction].1;
+
+            while file >= 0 && file < 8 && rank >= 0 && rank < 8 {
+                table[direction][square] |= 1 << (rank * 8 + file);
+
+                file += DIRECTIONS[direction].0;
+                rank += DIRECTIONS[direction].1;
+            }
+
+            square += 1;
+        }
+
+        direction += 1;
+    }
+
+    table
+}
+
+static KNIGHT_ATTACKS: [u64; 64] = step_attacks(&KNIGHT_STEPS);
+static KING_ATTACKS: [u64; 64] = step_attacks(&KING_STEPS);
+static PAWN_ATTACKS: [[u64; 64]; 2] = [step_attacks(&WHITE_PAWN_STEPS), step_attacks(&BLACK_PAWN_STEPS)];
+static RAYS: [[u64; 64]; 8] = rays();
+
+pub fn bit(square: u8) -> u64 {
+    1 << square
+}
+
+pub fn squares(bitboard: u64) -> Squares {
+    Squares(bitboard)
+}
+
+pub struct Squares(u64);
+
+impl Iterator for Squares {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        if self.0 == 0 {
+            return None;
+        }
+
+        let square = self.0.trailing_zeros() as u8;
+        self.0 &= self.0 - 1;
+
+        Some(square)
+    }
+}
+
+pub fn knight_attacks(square: u8) -> u64 {
+    KNIGHT_ATTACKS[square as usize]
+}
+
+pub fn king_attacks(square: u8) -> u64 {
+    KING_ATTACKS[square as usize]
+}
+
+pub fn pawn_attacks(color: Color, square: u8) -> u64 {
+    PAWN_ATTACKS[color as usize][square as usize]
+}
+
+fn ray_attacks(square: u8, direction: usize, occupied: u64) -> u64 {
+    let ray = RAYS[direction][square as usize];
+    let blockers = ray & occupied;
+
+    if blockers == 0 {
+        return ray;
+    }
+
+    // THE NEAREST BLOCKER IS THE LOWEST BIT ON POSITIVE RAYS AND THE HIGHEST ON NEGATIVE ONES
+    let blocker = if direction < 4 { blockers.trailing_zeros() } else { 63 - blockers.leading_zeros() };
+
+    ray ^ RAYS[direction][blocker as usize]
+}
+
+fn sliding_attacks(square: u8, occupied: u64, directions: &[usize]) -> u64 {
+    directions.iter().fold(0, |attacks, &direction| attacks | ray_attacks(square, direction, occupied))
+}
+
+// ---
+
+#[derive(Copy, Clone, Default)]
+struct Magic {
+    mask: u64,
+    magic: u64,
+    shift: u32,
+    offset: usize,
+}
+
+impl Magic {
+    fn index(&self, occupied: u64) -> usize {
+        self.offset + ((occupied & self.mask).wrapping_mul(self.magic) >> self.shift) as usize
+    }
+}
+
+struct MagicTables {
+    bishops: [Magic; 64],
+    rooks: [Magic; 64],
+    attacks: Vec<u64>,
+}
+
+static MAGIC_TABLES: OnceLock<MagicTables> = OnceLock::new();
+
+// ONE SEED PER RANK, PICKED SO THAT EVERY SQUARE FINDS ITS MAGIC WITHIN A FEW TRIES
+const MAGIC_SEEDS: [u64; 8] = [728, 10316, 55013, 32803, 12281, 15100, 16645, 255];
+
+// SPARSE RANDOM NUMBERS MAKE GOOD MAGIC CANDIDATES
+fn next_candidate(seed: &mut u64) -> u64 {
+    let mut random = || {
+        *seed ^= *seed >> 12;
+        *seed ^= *seed << 25;
+        *seed ^= *seed >> 27;
+
+        seed.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    };
+
+    random() & random() & random()
+}
+
+fn relevant_mask(square: u8, directions: &[usize]) -> u64 {
+    // THE LAST SQUARE OF A RAY NEVER BLOCKS ANYTHING BEHIND IT
+    directions.iter().fold(0, |mask, &direction| {
+        let ray = RAYS[direction][square as usize];
+
+        if ray == 0 {
+            return mask;
+        }
+
+        let edge: u64 = if direction < 4 { 1 << (63 - ray.leading_zeros()) } else { 1 << ray.trailing_zeros() };
+
+        mask | (ray & !edge)
+    })
+}
+
+fn find_magic(square: u8, directions: &[usize], attacks: &mut Vec<u64>, seed: &mut u64) -> Magic {
+    let mask = relevant_mask(square, directions);
+    let bits = mask.count_ones();
+
+    // EVERY SUBSET OF THE MASK TOGETHER WITH ITS ATTACKS
+    let mut occupancies = Vec::with_capacity(1 << bits);
+    let mut subset: u64 = 0;
+
+    loop {
+        occupancies.push((subset, sliding_attacks(square, subset, directions)));
+
+        subset = subset.wrapping_sub(mask) & mask;
+
+        if subset == 0 {
+            break;
+        }
+    }
+
+    let offset = attacks.len();
+    let size = 1 << bits;
+    attacks.resize(offset + size, 0);
+
+    let mut used = vec![0u32; size];
+    let mut attempt = 0;
+
+    loop {
+        let magic = next_candidate(seed);
+
+        if (mask.wrapping_mul(magic) >> 56).count_ones() < 6 {
+            continue;
+        }
+
+        attempt += 1;
+
+        let candidate = Magic { mask, magic, shift: 64 - bits, offset };
+
+        let fits = occupancies.iter().all(|&(occupied, attack)| {
+            let index = candidate.index(occupied) - offset;
+
+            if used[index] != attempt {
+                used[index] = attempt;
+                attacks[offset + index] = attack;
+
+                true
+            } else {
+                attacks[offset + index] == attack
+            }
+        });
+
+        if fits {
+            return candidate;
+        }
+    }
+}
+
+impl MagicTables {
+    fn new() -> MagicTables {
+        let mut attacks = Vec::new();
+
+        let mut bishops = [Magic::default(); 64];
+        let mut rooks = [Magic::default(); 64];
+
+        for square in 0..64 {
+            let seed = MAGIC_SEEDS[(square / 8) as usize];
+
+            bishops[square as usize] = find_magic(square, &BICHOP_DIRECTIONS, &mut attacks, &mut { seed });
+            rooks[square as usize] = find_magic(square, &ROOK_DIRECTIONS, &mut attacks, &mut { seed });
+        }
+
+        MagicTables {
+            bishops,
+            rooks,
+            attacks,
+        }
+    }
+}
+
+fn magic_tables() -> &'static MagicTables {
+    MAGIC_TABLES.get_or_init(MagicTables::new)
+}
+
+pub fn bishop_attacks(square: u8, occupied: u64) -> u64 {
+    let tables = magic_tables();
+
+    tables.attacks[tables.bishops[square as usize].index(occupied)]
+}
+
+pub fn rook_attacks(square: u8, occupied: u64) -> u64 {
+    let tables = magic_tables();
+
+    tables.attacks[tables.rooks[square as usize].index(occupied)]
+}
+
+pub fn queen_attacks(square: u8, occupied: u64) -> u64 {
+    bishop_attacks(square, occupied) | rook_attacks(square, occupied)
+}
+
+// ---
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct Bitboards {
+    roles: [u64; 6],
+    colors: [u64; 2],
+
+    castling: CastlingRights,
+
+    // MARKS THE SQUARE PASSED OVER BY A DOUBLE STEP PAWN
+    en_passent: u64,
+
+    // ZOBRIST KEY OF THE PIECES ALONE, KEPT UP TO DATE BY SET_PIECE
+    hash: u64,
+}
+
+impl Bitboards {
+    pub fn new() -> Bitboards {
+        Bitboards {
+            roles: [0; 6],
+            colors: [0; 2],
+            castling: CastlingRights::none(),
+            en_passent: 0,
+            hash: 0,
+        }
+    }
+
+    pub fn get_piece(&self, square: u8) -> Option<(Color, Role)> {
+        let mask = bit(square);
+
+        let color = if self.colors[Color::White as usize] & mask != 0 {
+            Color::White
+        } else if self.colors[Color::Black as usize] & mask != 0 {
+            Color::Black
+        } else {
+            return None;
+        };
+
+        ROLES.iter().find(|&&role| self.roles[role as usize] & mask != 0).map(|&role| (color, role))
+    }
+
+    pub fn set_piece(&mut self, square: u8, piece: Option<(Color, Role)>) -> Option<(Color, Role)> {
+        let captured_piece = self.get_piece(square);
+        let mask = bit(square);
+
+        if let Some((color, role)) = captured_piece {
+            self.colors[color as usize] &= !mask;
+            self.roles[role as usize] &= !mask;
+            self.hash ^= zobrist::piece(color, role, square);
+        }
+
+        if let Some((color, role)) = piece {
+            self.colors[color as usize] |= mask;
+            self.roles[role as usize] |= mask;
+            self.hash ^= zobrist::piece(color, role, square);
+        }
+
+        captured_piece
+    }
+
+    pub fn get_castling_rights(&self) -> CastlingRights {
+        self.castling
+    }
+
+    pub fn set_castling_rights(&mut self, castling: CastlingRights) {
+        self.castling = castling;
+    }
+
+    // MOVING FROM OR CAPTURING ON A HOME SQUARE OF KING OR ROOK LOSES THE RIGHTS TIED TO IT
+    pub fn remove_castling_rights(&mut self, square: u8) {
+        match square {
+            0 => self.castling.set_queenside(Color::White, false),
+            7 => self.castling.set_kingside(Color::White, false),
+            56 => self.castling.set_queenside(Color::Black, false),
+            63 => self.castling.set_kingside(Color::Black, false),
+            4 | 60 => {
+                let color = if square == 4 { Color::White } else { Color::Black };
+
+                self.castling.set_kingside(color, false);
+                self.castling.set_queenside(color, false);
+            },
+            _ => (),
+        }
+    }
+
+    pub fn get_hash(&self) -> u64 {
+        self.hash
+    }
+
+    pub fn get_en_passent(&self) -> Option<u8> {
+        squares(self.en_passent).next()
+    }
+
+    pub fn set_en_passent(&mut self, square: Option<u8>) {
+        self.en_passent = square.map(bit).unwrap_or(0);
+    }
+
+    pub fn get_occupied(&self) -> u64 {
+        self.colors[0] | self.colors[1]
+    }
+
+    pub fn get_color(&self, color: Color) -> u64 {
+        self.colors[color as usize]
+    }
+
+    pub fn get_pieces(&self, color: Color, role: Role) -> u64 {
+        self.colors[color as usize] & self.roles[role as usize]
+    }
+
+    pub fn get_king(&self, color: Color) -> Option<u8> {
+        squares(self.get_pieces(color, Role::King)).next()
+    }
+
+    pub fn is_attacked(&self, square: u8, by: Color) -> bool {
+        let occupied = self.get_occupied();
+
+        let diagonal = self.get_pieces(by, Role::Bichop) | self.get_pieces(by, Role::Queen);
+        let straight = self.get_pieces(by, Role::Rook) | self.get_pieces(by, Role::Queen);
+
+        // A PAWN ATTACKS THE SQUARE IF THE SQUARE WOULD ATTACK THE PAWN THE OTHER WAY ROUND
+        knight_attacks(square) & self.get_pieces(by, Role::Knight) != 0 ||
+            king_attacks(square) & self.get_pieces(by, Role::King) != 0 ||
+            pawn_attacks(by.opposite(), square) & self.get_pieces(by, Role::Pawn) != 0 ||
+            bishop_attacks(square, occupied) & diagonal != 0 ||
+            rook_attacks(square, occupied) & straight != 0
+    }
+
+    // ATTACKERS OF BOTH COLORS, SLIDERS SEE THROUGH EVERY SQUARE MISSING FROM OCCUPIED
+    pub fn attackers_to(&self, square: u8, occupied: u64) -> u64 {
+        let diagonal = self.roles[Role::Bichop as usize] | self.roles[Role::Queen as usize];
+        let straight = self.roles[Role::Rook as usize] | self.roles[Role::Queen as usize];
+
+        let attackers = (knight_attacks(square) & self.roles[Role::Knight as usize]) |
+            (king_attacks(square) & self.roles[Role::King as usize]) |
+            (pawn_attacks(Color::Black, square) & self.get_pieces(Color::White, Role::Pawn)) |
+            (pawn_attacks(Color::White, square) & self.get_pieces(Color::Black, Role::Pawn)) |
+            (bishop_attacks(square, occupied) & diagonal) |
+            (rook_attacks(square, occupied) & straight);
+
+        attackers & occupied
+    }
+
+    pub fn is_checked(&self, color: Color) -> bool {
+        match self.get_king(color) {
+            Some(king) => self.is_attacked(king, color.opposite()),
+            None => false,
+        }
+    }
+
+    fn _format(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Bitboards [ roles: {:x?}, colors: {:x?}, castling: {}, en_passent: {:x} ]", self.roles, self.colors, self.castling, self.en_passent)
+    }
+}
+
+impl fmt::Debug for Bitboards {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self._format(f)
+    }
+}
+impl fmt::Display for Bitboards {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self._format(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bitboard;
+    use crate::bitboard::Bitboards;
+    use crate::color::Color;
+    use crate::comp::CastlingRights;
+    use crate::role::Role;
+
+    #[test]
+    fn attacks() {
+        assert_eq!(bitboard::knight_attacks(0), bitboard::bit(10) | bitboard::bit(17));
+        assert_eq!(bitboard::king_attacks(0).count_ones(), 3);
+        assert_eq!(bitboard::pawn_attacks(Color::White, 8), bitboard::bit(17));
+        assert_eq!(bitboard::pawn_attacks(Color::Black, 15), bitboard::bit(6));
+        assert_eq!(bitboard::pawn_attacks(Color::White, 60), 0);
+
+        assert_eq!(bitboard::rook_attacks(0, 0).count_ones(), 14);
+        assert_eq!(bitboard::bishop_attacks(27, 0).count_ones(), 13);
+        assert_eq!(bitboard::rook_attacks(0, bitboard::bit(16) | bitboard::bit(2)), bitboard::bit(8) | bitboard::bit(16) | bitboard::bit(1) | bitboard::bit(2));
+        assert_eq!(bitboard::bishop_attacks(63, bitboard::bit(45)), bitboard::bit(54) | bitboard::bit(45));
+    }
+
+    #[test]
+    fn magics() {
+        let mut seed: u64 = 0x1234_5678;
+
+        for square in 0..64 {
+            for _ in 0..64 {
+                let occupied = bitboard::next_candidate(&mut seed) | bitboard::next_candidate(&mut seed);
+
+                assert_eq!(bitboard::bishop_attacks(square, occupied), bitboard::sliding_attacks(square, occupied, &bitboard::BICHOP_DIRECTIONS));
+                assert_eq!(bitboard::rook_attacks(square, occupied), bitboard::sliding_attacks(square, occupied, &bitboard::ROOK_DIRECTIONS));
+            }
+        }
+    }
+
+    #[test]
+    fn squares() {
+        let squares: Vec<u8> = bitboard::squares(bitboard::bit(3) | bitboard::bit(17) | bitboard::bit(63)).collect();
+
+        assert_eq!(squares, vec![3, 17, 63]);
+    }
+
+    #[test]
+    fn sets() {
+        let mut board = Bitboards::new();
+
+        assert_eq!(board.set_piece(4, Some((Color::White, Role::King))), None);
+        assert_eq!(board.get_piece(4), Some((Color::White, Role::King)));
+
+        assert_eq!(board.set_piece(4, Some((Color::Black, Role::Queen))), Some((Color::White, Role::King)));
+        assert_eq!(board.get_king(Color::White), None);
+
+        board.set_castling_rights(CastlingRights::all());
+        board.remove_castling_rights(63);
+
+        assert_eq!(board.get_castling_rights(), CastlingRights::new(true, true, false, true));
+
+        board.remove_castling_rights(4);
+        board.remove_castling_rights(20);
+
+        assert_eq!(board.get_castling_rights(), CastlingRights::new(false, false, false, true));
+
+        board.set_en_passent(Some(20));
+
+        assert_eq!(board.get_en_passent(), Some(20));
+    }
+
+    #[test]
+    fn is_attacked() {
+        let mut board = Bitboards::new();
+
+        board.set_piece(4, Some((Color::White, Role::King)));
+        board.set_piece(60, Some((Color::Black, Role::Rook)));
+
+        assert!(board.is_checked(Color::White));
+        assert!(board.is_attacked(12, Color::Black));
+
+        board.set_piece(12, Some((Color::White, Role::Pawn)));
+
+        assert!(!board.is_checked(Color::White));
+        assert!(board.is_attacked(21, Color::White));
+        assert!(!board.is_attacked(20, Color::White));
+    }
+}
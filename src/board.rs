@@ -2,226 +2,630 @@ use std::fmt;
 use std::collections::HashMap;
 use std::string::String;
 
+use crate::bitboard;
+use crate::bitboard::Bitboards;
 use crate::color::Color;
-use crate::piece::Piece;
+use crate::comp;
+use crate::eval;
+use crate::fen;
+use crate::fen::Fen;
 use crate::role::Role;
-use crate::square::Square;
-
+use crate::san;
+use crate::see;
+use crate::status;
+use crate::validate;
+use crate::zobrist;
+
+pub use crate::comp::CastlingRights;
+pub use crate::comp::ChessFile;
 pub use crate::comp::ChessPiece;
+pub use crate::comp::ChessRank;
 pub use crate::comp::ChessRole;
 pub use crate::comp::ChessSquare;
+pub use crate::comp::Move;
+pub use crate::error::ChessError;
+pub use crate::eval::EvalParams;
+pub use crate::fen::FenError;
+pub use crate::san::SanError;
+pub use crate::status::GameStatus;
+pub use crate::validate::PositionProblem;
+
+pub type MoveList = Vec<Move>;
 
+const PROMOTION_ROLES: [ChessRole; 4] = [ChessRole::Queen, ChessRole::Knight, ChessRole::Rook, ChessRole::Bichop];
+
+#[derive(Clone)]
 pub struct Board {
-    board: Vec<Square>,
+    board: Bitboards,
 
     active_color: Color,
 
     promotions: HashMap<Color, Role>,
+
+    halfmove_clock: u32,
+    fullmove_number: u32,
+
+    // ZOBRIST KEY OF THE CURRENT POSITION, KEPT UP TO DATE MOVE BY MOVE
+    hash: u64,
+
+    // HASHES OF EARLIER POSITIONS FOR REPETITION DETECTION
+    history: Vec<u64>,
 }
 
 impl Board {
     pub fn new() -> Board {
-        let mut board: Vec<Square> = Vec::new();
+        let mut board = Bitboards::new();
 
         // INIT BOARD AND PIECES
         for rank in 0..8 {
             for file in 0..8 {
                 if rank == 0 || rank == 1 || rank == 6 || rank == 7 {
-                    let color: Color;
-                    match rank {
-                        0 | 1 => color = Color::White,
-                        _ => color = Color::Black,
-                    }
-
-                    let role: Role;
-                    if rank == 1 || rank == 6 {
-                        role = Role::Pawn;
+                    let color = match rank {
+                        0 | 1 => Color::White,
+                        _ => Color::Black,
+                    };
+
+                    let role = if rank == 1 || rank == 6 {
+                        Role::Pawn
                     } else {
                         match file {
-                            4 => role = Role::King,
-                            3 => role = Role::Queen,
-                            2 | 5 => role = Role::Bichop,
-                            1 | 6 => role = Role::Knight,
-                            _ => role = Role::Rook,
+                            4 => Role::King,
+                            3 => Role::Queen,
+                            2 | 5 => Role::Bichop,
+                            1 | 6 => Role::Knight,
+                            _ => Role::Rook,
                         }
-                    }
+                    };
 
-                    board.push(Square::new(file as u8, rank as u8, Some(Piece::new(color, role, file as u8, rank as u8))));
-                } else {
-                    board.push(Square::new(file as u8, rank as u8, None));
+                    board.set_piece(rank * 8 + file, Some((color, role)));
                 }
             }
         }
 
+        board.set_castling_rights(CastlingRights::all());
+
         let mut promotions = HashMap::new();
         promotions.insert(Color::White, Role::Queen);
         promotions.insert(Color::Black, Role::Queen);
 
+        let hash = position_key(&board, Color::White);
+
         Board {
-            board: board,
+            board,
             active_color: Color::White,
-            promotions: promotions
+            promotions,
+            halfmove_clock: 0,
+            fullmove_number: 1,
+            hash,
+            history: Vec::new(),
         }
     }
 
     pub fn custom(custom_board: Vec<Option<ChessPiece>>) -> Board {
+        match Board::try_custom(custom_board) {
+            Ok(board) => board,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    pub fn try_custom(custom_board: Vec<Option<ChessPiece>>) -> Result<Board, ChessError> {
+        let board = Board::try_custom_relaxed(custom_board)?;
+        let problems = board.validate();
+
+        if !problems.is_empty() {
+            return Err(ChessError::InvalidPosition(problems));
+        }
+
+        Ok(board)
+    }
+
+    // ONLY THE BOARD SIZE IS CHECKED, MOVE GENERATION MAY MISBEHAVE ON IMPOSSIBLE POSITIONS
+    pub fn try_custom_relaxed(custom_board: Vec<Option<ChessPiece>>) -> Result<Board, ChessError> {
         if custom_board.len() != 64 {
-            panic!("Parameter board must be of length 64.");
+            return Err(ChessError::InvalidPosition(vec![PositionProblem::SquareCount(custom_board.len())]));
         }
 
-        let mut board: Vec<Square> = Vec::new();
+        let mut board = Bitboards::new();
 
         // INIT BOARD AND PIECES
-        for rank in 0..8 {
-            for file in 0..8 {
-                let piece: Option<Piece>;
-                match custom_board[rank * 8 + file] {
-                    Some(_piece) => piece = Some(Piece::new(_piece.get_color(), Role::from(format!("{}", _piece.get_role())), file as u8, rank as u8)),
-                    None => piece = None
-                }
+        for (square, piece) in custom_board.iter().enumerate() {
+            let piece = piece.map(|_piece| (_piece.get_color(), Role::from(_piece.get_role())));
 
-                board.push(Square::new(file as u8, rank as u8, piece));
-            }
+            board.set_piece(square as u8, piece);
         }
 
         let mut promotions = HashMap::new();
         promotions.insert(Color::White, Role::Queen);
         promotions.insert(Color::Black, Role::Queen);
 
-        Board {
-            board: board,
+        let hash = position_key(&board, Color::White);
+
+        Ok(Board {
+            board,
             active_color: Color::White,
-            promotions: promotions
+            promotions,
+            halfmove_clock: 0,
+            fullmove_number: 1,
+            hash,
+            history: Vec::new(),
+        })
+    }
+
+    pub fn from_fen(fen: &str) -> Result<Board, FenError> {
+        let board = Board::from_fen_relaxed(fen)?;
+        let problems = board.validate();
+
+        if !problems.is_empty() {
+            return Err(FenError::Position(problems));
         }
+
+        Ok(board)
+    }
+
+    pub fn from_fen_relaxed(fen: &str) -> Result<Board, FenError> {
+        let parsed = fen::parse(fen)?;
+
+        // THE PARSER ALWAYS YIELDS 64 SQUARES
+        let mut board = Board::try_custom_relaxed(parsed.pieces).unwrap();
+
+        board.active_color = parsed.active_color;
+        board.halfmove_clock = parsed.halfmove_clock;
+        board.fullmove_number = parsed.fullmove_number;
+
+        board.board.set_castling_rights(parsed.castling);
+        board.board.set_en_passent(parsed.en_passant.map(|square| square.get_index()));
+
+        board.hash = position_key(&board.board, board.active_color);
+
+        Ok(board)
+    }
+
+    pub fn to_fen(&self) -> String {
+        let en_passant = self.board.get_en_passent().map(ChessSquare::from_index);
+
+        fen::format(&Fen {
+            pieces: self.get_active_pieces(),
+            active_color: self.active_color,
+            castling: self.board.get_castling_rights(),
+            en_passant,
+            halfmove_clock: self.halfmove_clock,
+            fullmove_number: self.fullmove_number,
+        })
+    }
+
+    pub fn hash(&self) -> u64 {
+        self.hash
+    }
+
+    pub(crate) fn count_repetitions(&self) -> usize {
+        let key = self.hash();
+
+        // NO POSITION BEFORE THE LAST CAPTURE OR PAWN MOVE CAN RECUR
+        1 + self.history.iter().rev().take(self.halfmove_clock as usize).filter(|&&previous| previous == key).count()
+    }
+
+    #[cfg(feature = "serde")]
+    pub(crate) fn get_history(&self) -> &[u64] {
+        &self.history
+    }
+
+    #[cfg(feature = "serde")]
+    pub(crate) fn set_history(&mut self, history: Vec<u64>) {
+        self.history = history;
+    }
+
+    pub fn validate(&self) -> Vec<PositionProblem> {
+        validate::validate(self)
+    }
+
+    pub fn status(&self) -> GameStatus {
+        status::status(self)
+    }
+
+    pub fn attackers_to(&self, square: ChessSquare, color: Color) -> Vec<ChessSquare> {
+        bitboard::squares(see::attackers(&self.board, square.get_index(), color))
+            .map(ChessSquare::from_index)
+            .collect()
+    }
+
+    pub fn see(&self, from: ChessSquare, to: ChessSquare) -> i32 {
+        see::see(&self.board, from.get_index(), to.get_index())
     }
 
     pub fn get_active_color(&self) -> Color {
         self.active_color
     }
 
+    pub fn get_halfmove_clock(&self) -> u32 {
+        self.halfmove_clock
+    }
+
+    pub fn get_fullmove_number(&self) -> u32 {
+        self.fullmove_number
+    }
+
     pub fn get_promotion_setting(&self, color: Color) -> ChessRole {
-        ChessRole::from(format!("{}", *self.promotions.get(&color).unwrap()))
+        ChessRole::from(*self.promotions.get(&color).unwrap())
     }
 
     pub fn set_promotion_setting(&mut self, color: Color, role: ChessRole) {
-        if role == ChessRole::King || role == ChessRole::Pawn {
+        if self.try_set_promotion_setting(color, role).is_err() {
             panic!("A pawn cannot get upgraded to a new pawn or a king!");
         }
+    }
+
+    pub fn try_set_promotion_setting(&mut self, color: Color, role: ChessRole) -> Result<(), ChessError> {
+        if role == ChessRole::King || role == ChessRole::Pawn {
+            return Err(ChessError::InvalidPromotion(role));
+        }
 
         self.promotions.remove(&color);
-        self.promotions.insert(color, Role::from(format!("{}", role)));
+        self.promotions.insert(color, Role::from(role));
+
+        Ok(())
     }
 
-    fn _get_active_pieces(&self) -> Vec<Option<Piece>> {
-        self.board.iter().map(|&_square| _square.get_piece()).collect()
+    pub fn get_castling_rights(&self) -> CastlingRights {
+        self.board.get_castling_rights()
+    }
+
+    pub fn set_castling_rights(&mut self, castling: CastlingRights) {
+        if let Err(err) = self.try_set_castling_rights(castling) {
+            panic!("{}", err);
+        }
+    }
+
+    // RIGHTS NEED THE KING AND THE ROOK ON THEIR HOME SQUARES
+    pub fn try_set_castling_rights(&mut self, castling: CastlingRights) -> Result<(), ChessError> {
+        let mut board = self.board;
+        board.set_castling_rights(castling);
+
+        let problems = validate::castling_problems(&board);
+
+        if !problems.is_empty() {
+            return Err(ChessError::InvalidPosition(problems));
+        }
+
+        self.hash ^= castling_key(self.board.get_castling_rights()) ^ castling_key(castling);
+        self.board = board;
+
+        Ok(())
     }
 
     pub fn get_active_pieces(&self) -> Vec<Option<ChessPiece>> {
-        self.board.iter().map(|&_square| {
-                match _square.get_piece() {
-                    Some(_piece) => Some(ChessPiece::new(_piece.get_color(), ChessRole::from(format!("{}", _piece.get_role())))),
-                    None => None
-                }
+        (0..64).map(|square| {
+                self.board.get_piece(square).map(|(color, role)| ChessPiece::new(color, ChessRole::from(role)))
             }).collect()
     }
 
-    fn get_active_piece(&self, piece: ChessSquare) -> Option<Piece> {
-        let active_pieces = self._get_active_pieces();
+    fn get_active_piece(&self, piece: ChessSquare) -> Option<(Color, Role)> {
+        self.board.get_piece(piece.get_index())
+    }
+
+    pub(crate) fn get_bitboards(&self) -> &Bitboards {
+        &self.board
+    }
 
-        for i in 0..active_pieces.len() {
-            let _active_piece = active_pieces[i];
+    pub(crate) fn is_check(&self) -> bool {
+        self.board.is_checked(self.active_color)
+    }
 
-            if _active_piece.is_some() {
-                let active_piece = _active_piece.unwrap();
+    pub(crate) fn has_legal_moves(&self) -> bool {
+        bitboard::squares(self.board.get_color(self.active_color)).any(|square| {
+            match self.board.get_piece(square) {
+                Some((color, role)) => role.get_possible_moves(&self.board, square, color) != 0,
+                None => false,
+            }
+        })
+    }
 
-                if active_piece.equal(piece.get_file(), piece.get_rank()) {
-                    return Some(active_piece);
+    fn push_moves(&self, moves: &mut MoveList, square: u8, targets: u64) {
+        let (color, role) = match self.board.get_piece(square) {
+            Some(piece) => piece,
+            None => return,
+        };
+
+        let from = ChessSquare::from_index(square);
+        let piece = ChessPiece::new(color, ChessRole::from(role));
+
+        for target in bitboard::squares(targets) {
+            let to = ChessSquare::from_index(target);
+
+            let mut captured = self.board.get_piece(target).map(|(_, captured)| ChessRole::from(captured));
+            let mut flags = 0;
+
+            match role {
+                Role::Pawn if target % 8 != square % 8 && captured.is_none() => {
+                    captured = Some(ChessRole::Pawn);
+                    flags |= comp::EN_PASSENT;
+                },
+                Role::Pawn if (target as i8 - square as i8).abs() == 16 => flags |= comp::DOUBLE_PUSH,
+                Role::King if (target as i8 - square as i8).abs() == 2 => flags |= comp::CASTLING,
+                _ => (),
+            }
+
+            // EVERY PROMOTION IS A MOVE OF ITS OWN
+            if role == Role::Pawn && (to.get_rank() == 0 || to.get_rank() == 7) {
+                for &promotion in PROMOTION_ROLES.iter() {
+                    moves.push(Move::new(from, to, Some(promotion)).with_details(piece, captured, flags));
                 }
+            } else {
+                moves.push(Move::new(from, to, None).with_details(piece, captured, flags));
             }
         }
+    }
 
-        println!("HEJ!");
+    pub fn get_possible_moves(&self, piece_position: ChessSquare) -> Option<Vec<Move>> {
+        let square = piece_position.get_index();
 
-        return None;
+        self.get_active_piece(piece_position).map(|(color, role)| {
+            let mut moves = Vec::new();
+
+            self.push_moves(&mut moves, square, role.get_possible_moves(&self.board, square, color));
+
+            moves
+        })
     }
 
-    fn copy_board(&self) -> [Square; 64] {
-        let mut board = [self.board[0]; 64];
+    pub fn legal_moves(&self) -> MoveList {
+        let mut moves = MoveList::new();
 
-        for i in 1..self.board.len() {
-            board[i] = self.board[i];
+        for square in bitboard::squares(self.board.get_color(self.active_color)) {
+            if let Some((color, role)) = self.board.get_piece(square) {
+                self.push_moves(&mut moves, square, role.get_possible_moves(&self.board, square, color));
+            }
         }
 
-        board
+        moves
     }
 
-    pub fn get_possible_moves(&self, piece_position: ChessSquare) -> Option<Vec<ChessSquare>> {
-        match self.get_active_piece(piece_position) {
-            Some(_piece) => Some(_piece.get_possible_moves(self.copy_board()).iter().map(|&_square| ChessSquare::new(_square.get_file(), _square.get_rank())).collect()),
-            None => None
+    // MOVES THAT MAY LEAVE THE OWN KING IN CHECK
+    pub fn pseudo_legal_moves(&self) -> MoveList {
+        let mut moves = MoveList::new();
+
+        for square in bitboard::squares(self.board.get_color(self.active_color)) {
+            if let Some((color, role)) = self.board.get_piece(square) {
+                self.push_moves(&mut moves, square, role.get_pseudo_legal_moves(&self.board, square, color));
+            }
         }
+
+        moves
     }
 
-    pub fn move_piece_to(&mut self, current_position: ChessSquare, target_position: ChessSquare) -> Result<Option<ChessPiece>, String> {
+    pub fn captures_only(&self) -> MoveList {
+        self.legal_moves().into_iter().filter(|chess_move| chess_move.is_capture()).collect()
+    }
+
+    pub fn quiet_only(&self) -> MoveList {
+        self.legal_moves().into_iter().filter(|chess_move| !chess_move.is_capture()).collect()
+    }
 
-        let target_rank = target_position.get_rank();
-        let target_file = target_position.get_file();
+    // EMPTY WHEN THE SIDE TO MOVE IS NOT IN CHECK
+    pub fn evasions(&self) -> MoveList {
+        if self.is_check() { self.legal_moves() } else { MoveList::new() }
+    }
+
+    pub fn perft(&self, depth: u32) -> u64 {
+        perft(&self.board, self.active_color, depth)
+    }
 
-        let mut piece: Piece;
-        match self.get_active_piece(current_position) {
-            Some(_piece) => piece = _piece,
-            None => return Err("No piece found on current position.".to_string()),
+    pub fn perft_divide(&self, depth: u32) -> Vec<(Move, u64)> {
+        if depth == 0 {
+            return Vec::new();
         }
 
-        let result = piece.move_to(self.copy_board(), target_file, target_rank);
+        self.legal_moves().into_iter().map(|chess_move| {
+            let square = chess_move.get_from().get_index();
+            let target = chess_move.get_to().get_index();
 
-        match result.0 {
-            Ok(_captured_piece) => {
-                // APPLY BOARD CHANGES
-                // TODO: test without application
-                self.board.clear();
-                for _square in result.1.iter() {
-                    self.board.push(*_square);
-                }
+            let child = perft_child(&self.board, square, target, self.active_color, chess_move.get_promotion().map(Role::from));
 
-                // PROMOTION
-                if piece.get_role() == Role::Pawn && (current_position.get_rank() == 7 || current_position.get_rank() == 0) {
-                    self.board[(target_rank * 8 + target_file) as usize].set_piece(Some(Piece::new(self.active_color, *self.promotions.get(&self.active_color).unwrap(), target_file, target_rank)));
-                }
+            (chess_move, perft(&child, self.active_color.opposite(), depth - 1))
+        }).collect()
+    }
 
-                let active_pieces = self._get_active_pieces();
+    pub fn evaluate(&self, params: &EvalParams) -> i32 {
+        eval::evaluate(self, params)
+    }
 
-                // CHECK FOR CHECK MATE
-                for i in 0..active_pieces.len() {
-                    let _active_piece = active_pieces[i];
+    pub fn to_san(&self, current_position: ChessSquare, target_position: ChessSquare, promotion: Option<ChessRole>) -> Result<String, SanError> {
+        san::format(self, current_position, target_position, promotion)
+    }
 
-                    if _active_piece.is_some() {
-                        let active_piece = _active_piece.unwrap();
+    pub fn parse_san(&self, san: &str) -> Result<(ChessSquare, ChessSquare, Option<ChessRole>), SanError> {
+        san::parse(self, san)
+    }
 
-                        if active_piece.get_color() != self.active_color && active_piece.get_role() == Role::King {
-                            if self.get_possible_moves(ChessSquare::new(active_piece.get_file(), active_piece.get_rank())).unwrap().len() == 0 &&
-                                active_piece.get_role().is_position_checked(self.copy_board(), active_piece.get_file(), active_piece.get_rank(), self.active_color, active_piece.has_moved()) {
-                                return Ok(Some(ChessPiece::new(active_piece.get_color(), ChessRole::from(format!("{}", active_piece.get_role())))));
-                            }
+    pub fn to_uci(&self, current_position: ChessSquare, target_position: ChessSquare, promotion: Option<ChessRole>) -> Result<String, SanError> {
+        san::format_uci(self, current_position, target_position, promotion)
+    }
 
-                            break;
-                        }
-                    }
-                }
+    pub fn parse_uci(&self, uci: &str) -> Result<(ChessSquare, ChessSquare, Option<ChessRole>), SanError> {
+        san::parse_uci(self, uci)
+    }
 
-                // TOGGLE ACTIVE COLOR
-                match self.active_color {
-                    Color::White => self.active_color = Color::Black,
-                    _ => self.active_color = Color::White,
-                }
+    fn check_move(&self, current_position: ChessSquare, target_position: ChessSquare) -> Result<(u8, u8), ChessError> {
+        let current_square = current_position.get_index();
+        let target_square = target_position.get_index();
 
-                match _captured_piece {
-                    Some(_piece) => Ok(Some(ChessPiece::new(_piece.get_color(), ChessRole::from(format!("{}", _piece.get_role()))))),
-                    None => Ok(None)
-                }
-            },
-            Err(_err) => Err(_err)
+        let (color, role) = match self.get_active_piece(current_position) {
+            Some(_piece) => _piece,
+            None => return Err(ChessError::NoPieceAt(current_position)),
+        };
+
+        if color != self.active_color {
+            return Err(ChessError::NotYourTurn);
+        }
+
+        if role.get_possible_moves(&self.board, current_square, color) & bitboard::bit(target_square) == 0 {
+            return Err(ChessError::IllegalMove(Move::new(current_position, target_position, None)));
+        }
+
+        Ok((current_square, target_square))
+    }
+
+    // PROMOTING PAWNS FALL BACK ON THE PROMOTION SETTING
+    fn apply_move(&mut self, square: u8, target: u8, promotion: Option<Role>) -> UndoInfo {
+        let (color, role) = self.board.get_piece(square).expect("No piece found on current position.");
+
+        // EN PASSENT CAPTURES THE PAWN BESIDE THE MOVING ONE
+        let captured_square = if role == Role::Pawn && square % 8 != target % 8 && self.board.get_piece(target).is_none() {
+            square / 8 * 8 + target % 8
+        } else {
+            target
+        };
+
+        self.history.push(self.hash);
+
+        // THE EN PASSENT KEY DEPENDS ON THE PAWNS BEFORE THE MOVE
+        let pieces = self.board.get_hash();
+        let en_passent = en_passent_key(&self.board, self.active_color);
+
+        let undo = UndoInfo {
+            role,
+            captured: self.board.get_piece(captured_square),
+            captured_square,
+            castling: self.board.get_castling_rights(),
+            en_passent: self.board.get_en_passent(),
+            halfmove_clock: self.halfmove_clock,
+            fullmove_number: self.fullmove_number,
+            hash: self.hash,
+        };
+
+        role.make_move(&mut self.board, square, target, color);
+
+        // UPDATE MOVE CLOCKS
+        if role == Role::Pawn || undo.captured.is_some() {
+            self.halfmove_clock = 0;
+        } else {
+            self.halfmove_clock += 1;
+        }
+
+        if color == Color::Black {
+            self.fullmove_number += 1;
+        }
+
+        // PROMOTION
+        if role == Role::Pawn && (target / 8 == 7 || target / 8 == 0) {
+            let promoted = promotion.unwrap_or(*self.promotions.get(&color).unwrap());
+
+            self.board.set_piece(target, Some((color, promoted)));
+        }
+
+        // TOGGLE ACTIVE COLOR
+        self.active_color = color.opposite();
+
+        // ONLY WHAT THE MOVE CHANGED GOES INTO THE KEY
+        self.hash ^= pieces ^ self.board.get_hash();
+        self.hash ^= castling_key(undo.castling) ^ castling_key(self.board.get_castling_rights());
+        self.hash ^= en_passent ^ en_passent_key(&self.board, self.active_color);
+        self.hash ^= zobrist::white_to_move();
+
+        undo
+    }
+
+    pub fn make_move(&mut self, chess_move: Move) -> UndoInfo {
+        match self.try_make_move(chess_move) {
+            Ok(undo) => undo,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    pub fn try_make_move(&mut self, chess_move: Move) -> Result<UndoInfo, ChessError> {
+        let (square, target) = self.check_move(chess_move.get_from(), chess_move.get_to()).map_err(|err| match err {
+            ChessError::IllegalMove(_) => ChessError::IllegalMove(chess_move),
+            _ => err,
+        })?;
+
+        Ok(self.apply_move(square, target, chess_move.get_promotion().map(Role::from)))
+    }
+
+    // THE MOVE MUST ALREADY BE KNOWN TO BE LEGAL
+    pub(crate) fn make_move_unchecked(&mut self, chess_move: Move) -> UndoInfo {
+        let (from, to) = (chess_move.get_from(), chess_move.get_to());
+
+        self.apply_move(from.get_index(), to.get_index(), chess_move.get_promotion().map(Role::from))
+    }
+
+    pub fn unmake_move(&mut self, chess_move: Move, undo: UndoInfo) {
+        let (from, to) = (chess_move.get_from(), chess_move.get_to());
+
+        let square = from.get_index();
+        let target = to.get_index();
+
+        let color = self.active_color.opposite();
+
+        // PROMOTED PIECES TURN BACK INTO THE PAWN THAT MOVED
+        self.board.set_piece(target, None);
+        self.board.set_piece(square, Some((color, undo.role)));
+
+        if let Some(captured) = undo.captured {
+            self.board.set_piece(undo.captured_square, Some(captured));
+        }
+
+        // CASTLING
+        if undo.role == Role::King && ((target as i8) - (square as i8)).abs() == 2 {
+            let (rook_file, rook_target_file) = if target < square { (0, 3) } else { (7, 5) };
+
+            self.board.set_piece(square / 8 * 8 + rook_target_file, None);
+            self.board.set_piece(square / 8 * 8 + rook_file, Some((color, Role::Rook)));
+        }
+
+        self.board.set_castling_rights(undo.castling);
+        self.board.set_en_passent(undo.en_passent);
+
+        self.halfmove_clock = undo.halfmove_clock;
+        self.fullmove_number = undo.fullmove_number;
+        self.active_color = color;
+
+        self.hash = undo.hash;
+        self.history.pop();
+    }
+
+    pub(crate) fn move_piece_with_promotion(&mut self, current_position: ChessSquare, target_position: ChessSquare, promotion: Option<ChessRole>) -> Result<MoveOutcome, ChessError> {
+        if let Some(role @ ChessRole::King) | Some(role @ ChessRole::Pawn) = promotion {
+            return Err(ChessError::InvalidPromotion(role));
+        }
+
+        let (current_square, target_square) = self.check_move(current_position, target_position)?;
+        let (color, role) = self.board.get_piece(current_square).unwrap();
+
+        // PROMOTING PAWNS FALL BACK ON THE PROMOTION SETTING, OTHER MOVES IGNORE THE CHOICE
+        let promotion = if role == Role::Pawn && (target_square / 8 == 7 || target_square / 8 == 0) {
+            Some(promotion.unwrap_or_else(|| self.get_promotion_setting(color)))
+        } else {
+            None
+        };
+
+        let mut san = san::format_move(self, current_position, target_position, promotion)?;
+
+        let undo = self.apply_move(current_square, target_square, promotion.map(Role::from));
+
+        let status = self.status();
+        let check = self.is_check();
+
+        if check {
+            san.push(if let GameStatus::Checkmate(_) = status { '#' } else { '+' });
         }
+
+        Ok(MoveOutcome {
+            captured: undo.get_captured(),
+            castling: role == Role::King && ((target_square as i8) - (current_square as i8)).abs() == 2,
+            en_passent: undo.captured_square != target_square,
+            promotion,
+            check,
+            san,
+            status,
+        })
+    }
+
+    pub fn move_piece_to(&mut self, current_position: ChessSquare, target_position: ChessSquare) -> Result<MoveOutcome, ChessError> {
+        self.move_piece_with_promotion(current_position, target_position, None)
+    }
+
+    // A MOVE WITHOUT A PROMOTION ROLE PROMOTES BY THE PROMOTION SETTING
+    pub fn play(&mut self, chess_move: Move) -> Result<MoveOutcome, ChessError> {
+        self.move_piece_with_promotion(chess_move.get_from(), chess_move.get_to(), chess_move.get_promotion())
     }
 
     fn _format(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -229,13 +633,160 @@ impl Board {
 
         let board_active_pieces = self.get_active_pieces();
 
-        for i in 0..board_active_pieces.len() {
-            active_pieces.push_str(format!("{:?}, ", board_active_pieces[i]).as_str());
+        for board_active_piece in board_active_pieces.iter() {
+            active_pieces.push_str(format!("{:?}, ", board_active_piece).as_str());
         }
 
         active_pieces.push_str(" ]");
 
-        write!(f, "Board [ board: [Square; 64], active_pieces: {}, active_color: {}, promotions: [Color::White -> {}, Color::Black -> {}] ]", active_pieces, self.active_color, self.promotions.get(&Color::White).unwrap(), self.promotions.get(&Color::Black).unwrap())
+        write!(f, "Board [ board: {}, active_pieces: {}, active_color: {}, promotions: [Color::White -> {}, Color::Black -> {}] ]", self.board, active_pieces, self.active_color, self.promotions.get(&Color::White).unwrap(), self.promotions.get(&Color::Black).unwrap())
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct UndoInfo {
+    role: Role,
+
+    captured: Option<(Color, Role)>,
+    captured_square: u8,
+
+    castling: CastlingRights,
+    en_passent: Option<u8>,
+
+    halfmove_clock: u32,
+    fullmove_number: u32,
+
+    hash: u64,
+}
+
+impl UndoInfo {
+    pub fn get_captured(&self) -> Option<ChessPiece> {
+        self.captured.map(|(color, role)| ChessPiece::new(color, ChessRole::from(role)))
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct MoveOutcome {
+    captured: Option<ChessPiece>,
+
+    castling: bool,
+    en_passent: bool,
+    promotion: Option<ChessRole>,
+
+    check: bool,
+    san: String,
+    status: GameStatus,
+}
+
+impl MoveOutcome {
+    pub fn get_captured(&self) -> Option<ChessPiece> {
+        self.captured
+    }
+
+    pub fn is_castling(&self) -> bool {
+        self.castling
+    }
+
+    pub fn is_en_passent(&self) -> bool {
+        self.en_passent
+    }
+
+    pub fn get_promotion(&self) -> Option<ChessRole> {
+        self.promotion
+    }
+
+    pub fn is_check(&self) -> bool {
+        self.check
+    }
+
+    pub fn is_checkmate(&self) -> bool {
+        matches!(self.status, GameStatus::Checkmate(_))
+    }
+
+    pub fn get_san(&self) -> &str {
+        &self.san
+    }
+
+    pub fn get_status(&self) -> GameStatus {
+        self.status
+    }
+}
+
+fn castling_key(castling: CastlingRights) -> u64 {
+    castling.indices().fold(0, |key, index| key ^ zobrist::castling(index))
+}
+
+// EN PASSENT ONLY COUNTS WHEN A PAWN COULD TAKE
+fn en_passent_key(board: &Bitboards, active_color: Color) -> u64 {
+    board.get_en_passent()
+        .filter(|&square| bitboard::pawn_attacks(active_color.opposite(), square) & board.get_pieces(active_color, Role::Pawn) != 0)
+        .map_or(0, |square| zobrist::en_passent(square % 8))
+}
+
+fn position_key(board: &Bitboards, active_color: Color) -> u64 {
+    let mut key = board.get_hash() ^ castling_key(board.get_castling_rights()) ^ en_passent_key(board, active_color);
+
+    if active_color == Color::White {
+        key ^= zobrist::white_to_move();
+    }
+
+    key
+}
+
+fn perft_child(board: &Bitboards, square: u8, target: u8, active_color: Color, promotion: Option<Role>) -> Bitboards {
+    let mut child = *board;
+
+    if let Some((_, role)) = board.get_piece(square) {
+        role.make_move(&mut child, square, target, active_color);
+    }
+
+    if let Some(role) = promotion {
+        child.set_piece(target, Some((active_color, role)));
+    }
+
+    child
+}
+
+fn perft(board: &Bitboards, active_color: Color, depth: u32) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+
+    let mut nodes = 0;
+
+    for square in bitboard::squares(board.get_color(active_color)) {
+        let role = match board.get_piece(square) {
+            Some((_, role)) => role,
+            None => continue,
+        };
+
+        for target in bitboard::squares(role.get_possible_moves(board, square, active_color)) {
+            let promotions: &[Option<Role>] = if role == Role::Pawn && (target / 8 == 0 || target / 8 == 7) {
+                &[Some(Role::Queen), Some(Role::Rook), Some(Role::Bichop), Some(Role::Knight)]
+            } else {
+                &[None]
+            };
+
+            // LEAF MOVES ONLY NEED COUNTING
+            if depth == 1 {
+                nodes += promotions.len() as u64;
+                continue;
+            }
+
+            for &promotion in promotions {
+                let child = perft_child(board, square, target, active_color, promotion);
+
+                nodes += perft(&child, active_color.opposite(), depth - 1);
+            }
+        }
+    }
+
+    nodes
+}
+
+impl Default for Board {
+    fn default() -> Self {
+        Board::new()
     }
 }
 
@@ -253,10 +804,41 @@ impl fmt::Display for Board {
 #[cfg(test)]
 mod tests {
     use crate::board::Board;
+    use crate::board::CastlingRights;
+    use crate::board::ChessError;
+    use crate::board::FenError;
+    use crate::board::GameStatus;
+    use crate::board::PositionProblem;
+    use crate::fen;
     use crate::color::Color;
     use crate::comp::ChessRole;
     use crate::comp::ChessSquare;
     use crate::comp::ChessPiece;
+    use crate::comp::Move;
+
+    fn targets(board: &Board, square: ChessSquare) -> Vec<ChessSquare> {
+        board.get_possible_moves(square).unwrap().iter().map(|chess_move| chess_move.get_to()).collect()
+    }
+
+    fn unmake_walk(board: &mut Board, depth: u32) {
+        if depth == 0 {
+            return;
+        }
+
+        for chess_move in board.legal_moves() {
+            let before = board.clone();
+
+            let undo = board.make_move(chess_move);
+            assert_eq!(board.hash(), Board::from_fen_relaxed(&board.to_fen()).unwrap().hash(), "{}", chess_move);
+
+            unmake_walk(board, depth - 1);
+            board.unmake_move(chess_move, undo);
+
+            assert!(board.board == before.board, "{}", chess_move);
+            assert_eq!(board.hash(), before.hash());
+            assert_eq!(board.to_fen(), before.to_fen());
+        }
+    }
 
     #[test]
     fn r#const() {
@@ -279,6 +861,19 @@ mod tests {
 
         assert_eq!(board.get_promotion_setting(Color::White), ChessRole::Bichop);
         assert_eq!(board.get_promotion_setting(Color::Black), ChessRole::Queen);
+
+        board.set_castling_rights(CastlingRights::new(false, true, true, false));
+
+        assert_eq!(board.get_castling_rights(), CastlingRights::new(false, true, true, false));
+        assert_eq!(board.to_fen(), "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w Qk - 0 1");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid position: White has castling rights without its king and rook on their home squares.")]
+    fn sets_castling_err() {
+        let mut board = Board::from_fen("4k3/8/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+
+        board.set_castling_rights(CastlingRights::all());
     }
 
     #[test]
@@ -298,26 +893,475 @@ mod tests {
     }
 
     #[test]
-    #[ignore]
+    fn try_sets() {
+        let mut board = Board::new();
+
+        assert_eq!(board.try_set_promotion_setting(Color::Black, ChessRole::Knight), Ok(()));
+        assert_eq!(board.try_set_promotion_setting(Color::White, ChessRole::King), Err(ChessError::InvalidPromotion(ChessRole::King)));
+        assert_eq!(board.get_promotion_setting(Color::Black), ChessRole::Knight);
+        assert_eq!(board.get_promotion_setting(Color::White), ChessRole::Queen);
+
+        assert!(Board::try_custom(Board::new().get_active_pieces()).is_ok());
+        assert!(Board::try_custom_relaxed(vec![None; 64]).is_ok());
+        assert_eq!(Board::try_custom(vec![None; 63]).err(), Some(ChessError::InvalidPosition(vec![PositionProblem::SquareCount(63)])));
+        assert_eq!(Board::try_custom_relaxed(vec![None; 65]).err(), Some(ChessError::InvalidPosition(vec![PositionProblem::SquareCount(65)])));
+
+        let problems = vec![PositionProblem::KingCount(Color::White, 0), PositionProblem::KingCount(Color::Black, 0)];
+
+        assert_eq!(Board::try_custom(vec![None; 64]).err(), Some(ChessError::InvalidPosition(problems)));
+
+        // CUSTOM BOARDS START WITHOUT CASTLING RIGHTS
+        let mut board = Board::custom(Board::new().get_active_pieces());
+
+        assert_eq!(board.get_castling_rights(), CastlingRights::none());
+        assert_eq!(board.try_set_castling_rights(CastlingRights::all()), Ok(()));
+        assert_eq!(board.hash(), Board::new().hash());
+
+        let mut board = Board::from_fen("r3k3/8/8/8/8/8/8/4K2R w - - 0 1").unwrap();
+
+        assert_eq!(board.try_set_castling_rights(CastlingRights::all()), Err(ChessError::InvalidPosition(vec![PositionProblem::InvalidCastling(Color::White), PositionProblem::InvalidCastling(Color::Black)])));
+        assert_eq!(board.get_castling_rights(), CastlingRights::none());
+        assert_eq!(board.hash(), Board::from_fen("r3k3/8/8/8/8/8/8/4K2R w - - 0 1").unwrap().hash());
+    }
+
+    #[test]
     fn get_possible_moves() {
         let board = Board::new();
 
         let result = board.get_possible_moves(ChessSquare::new(0, 1)).unwrap();
-        let expected = vec![ChessSquare::new(0, 3), ChessSquare::new(0, 2)];
+        let expected = [Move::new(ChessSquare::new(0, 1), ChessSquare::new(0, 2), None), Move::new(ChessSquare::new(0, 1), ChessSquare::new(0, 3), None)];
+
+        assert_eq!(result, expected);
+        assert_eq!(targets(&board, ChessSquare::new(6, 0)), [ChessSquare::new(5, 2), ChessSquare::new(7, 2)]);
+        assert_eq!(targets(&board, ChessSquare::new(4, 0)), []);
+        assert_eq!(board.get_possible_moves(ChessSquare::new(4, 4)), None);
+
+        // EVERY PROMOTION IS LISTED ON ITS OWN
+        let board = Board::from_fen("1r2k3/P7/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+        let moves = board.get_possible_moves(ChessSquare::new(0, 6)).unwrap();
 
-        for i in 0..result.len() {
-            assert_eq!(result[i], expected[i])
+        assert_eq!(moves.len(), 8);
+
+        for &role in [ChessRole::Queen, ChessRole::Rook, ChessRole::Bichop, ChessRole::Knight].iter() {
+            assert!(moves.contains(&Move::new(ChessSquare::new(0, 6), ChessSquare::new(0, 7), Some(role))));
+            assert!(moves.contains(&Move::new(ChessSquare::new(0, 6), ChessSquare::new(1, 7), Some(role))));
         }
     }
 
     #[test]
-    #[ignore]
+    fn get_possible_moves_special() {
+        // CASTLING BOTH WAYS, BUT NOT THROUGH THE ATTACKED d1 SQUARE
+        let board = Board::from_fen("r3k2r/8/8/8/8/8/3r4/R3K2R w KQkq - 0 1").unwrap();
+        let moves = targets(&board, ChessSquare::new(4, 0));
+
+        assert!(moves.contains(&ChessSquare::new(6, 0)));
+        assert!(!moves.contains(&ChessSquare::new(2, 0)));
+
+        // PAWNS DO NOT PUSH THROUGH BLOCKERS
+        let board = Board::from_fen("4k3/8/8/8/8/4n3/4P3/4K3 w - - 0 1").unwrap();
+
+        assert_eq!(targets(&board, ChessSquare::new(4, 1)), []);
+
+        // PINNED PIECES STAY PUT
+        let board = Board::from_fen("4k3/4r3/8/8/8/8/4N3/4K3 w - - 0 1").unwrap();
+
+        assert_eq!(targets(&board, ChessSquare::new(4, 1)), []);
+    }
+
+    #[test]
+    fn legal_moves() {
+        let moves = Board::new().legal_moves();
+
+        assert_eq!(moves.len(), 20);
+        assert_eq!(moves.iter().filter(|chess_move| chess_move.is_double_push()).count(), 8);
+        assert!(moves.iter().all(|chess_move| chess_move.get_piece().unwrap().get_color() == Color::White));
+        assert!(moves.contains(&Move::new(ChessSquare::new(6, 0), ChessSquare::new(5, 2), None)));
+
+        let board = Board::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1").unwrap();
+
+        assert_eq!(board.legal_moves().len(), 48);
+        assert_eq!(board.captures_only().len(), 8);
+        assert_eq!(board.quiet_only().len(), 40);
+        assert_eq!(board.legal_moves().iter().filter(|chess_move| chess_move.is_castling()).count(), 2);
+        assert_eq!(board.evasions(), []);
+
+        let capture = board.captures_only().into_iter().find(|chess_move| chess_move.get_to() == ChessSquare::new(0, 5)).unwrap();
+
+        assert_eq!(capture.get_piece(), Some(ChessPiece::new(Color::White, ChessRole::Bichop)));
+        assert_eq!(capture.get_captured(), Some(ChessRole::Bichop));
+
+        // EN PASSENT AND PROMOTIONS
+        let board = Board::from_fen("1r2k3/P7/8/3pP3/8/8/8/4K3 w - d6 0 1").unwrap();
+        let en_passent = board.captures_only().into_iter().find(|chess_move| chess_move.is_en_passent()).unwrap();
+
+        assert_eq!(en_passent.get_to(), ChessSquare::new(3, 5));
+        assert_eq!(en_passent.get_captured(), Some(ChessRole::Pawn));
+        assert_eq!(board.legal_moves().iter().filter(|chess_move| chess_move.is_promotion()).count(), 8);
+        assert_eq!(board.captures_only().iter().filter(|chess_move| chess_move.is_promotion()).count(), 4);
+    }
+
+    #[test]
+    fn pseudo_legal_moves() {
+        // THE PINNED KNIGHT MOVES ARE ONLY PSEUDO LEGAL
+        let board = Board::from_fen("4k3/4r3/8/8/8/8/4N3/4K3 w - - 0 1").unwrap();
+
+        assert_eq!(board.legal_moves().len(), 4);
+        assert_eq!(board.pseudo_legal_moves().len(), 10);
+        assert!(board.legal_moves().iter().all(|chess_move| board.pseudo_legal_moves().contains(chess_move)));
+    }
+
+    #[test]
+    fn evasions() {
+        let board = Board::from_fen("4k3/8/8/8/8/5n2/8/R3K3 w Q - 0 1").unwrap();
+        let evasions = board.evasions();
+
+        assert_eq!(evasions, board.legal_moves());
+        assert!(evasions.iter().all(|chess_move| chess_move.get_piece().unwrap().get_role() == ChessRole::King));
+        assert!(!evasions.iter().any(|chess_move| chess_move.is_castling()));
+    }
+
+    #[test]
+    fn castling_rights() {
+        let castles = |board: &Board| board.legal_moves().iter().filter(|chess_move| chess_move.is_castling()).count();
+
+        // RIGHTS DECIDE, NOT WHETHER KING AND ROOKS HAVE MOVED
+        let mut board = Board::custom(Board::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w - - 0 1").unwrap().get_active_pieces());
+
+        assert_eq!(castles(&board), 0);
+
+        board.set_castling_rights(CastlingRights::new(true, false, true, true));
+
+        assert_eq!(castles(&board), 1);
+
+        // A ROOK MOVE OR CAPTURE TAKES AWAY ITS SIDE, A KING MOVE BOTH
+        let mut board = Board::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1").unwrap();
+        let undo = board.make_move(Move::new(ChessSquare::new(0, 0), ChessSquare::new(0, 7), None));
+
+        assert_eq!(board.get_castling_rights(), CastlingRights::new(true, false, true, false));
+
+        board.unmake_move(Move::new(ChessSquare::new(0, 0), ChessSquare::new(0, 7), None), undo);
+
+        assert_eq!(board.get_castling_rights(), CastlingRights::all());
+
+        board.make_move(Move::new(ChessSquare::new(4, 0), ChessSquare::new(4, 1), None));
+
+        assert_eq!(board.get_castling_rights(), CastlingRights::new(false, false, true, true));
+        assert_eq!(board.to_fen(), "r3k2r/8/8/8/8/8/4K3/R6R b kq - 1 1");
+    }
+
+    #[test]
+    fn move_piece_to_special() {
+        // CASTLING MOVES THE ROOK
+        let mut board = Board::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1").unwrap();
+        let outcome = board.move_piece_to(ChessSquare::new(4, 0), ChessSquare::new(6, 0)).unwrap();
+
+        assert!(outcome.is_castling());
+        assert_eq!(outcome.get_san(), "O-O");
+
+        let outcome = board.move_piece_to(ChessSquare::new(4, 7), ChessSquare::new(2, 7)).unwrap();
+
+        assert!(outcome.is_castling() && !outcome.is_check());
+        assert_eq!(outcome.get_san(), "O-O-O");
+
+        assert_eq!(board.to_fen(), "2kr3r/8/8/8/8/8/8/R4RK1 w - - 2 2");
+
+        // EN PASSENT REMOVES THE PASSED PAWN
+        let mut board = Board::from_fen("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 2").unwrap();
+        let outcome = board.move_piece_to(ChessSquare::new(4, 4), ChessSquare::new(3, 5)).unwrap();
+
+        assert_eq!(outcome.get_captured(), Some(ChessPiece::new(Color::Black, ChessRole::Pawn)));
+        assert!(outcome.is_en_passent());
+        assert_eq!(outcome.get_san(), "exd6");
+        assert_eq!(board.to_fen(), "4k3/8/3P4/8/8/8/8/4K3 b - - 0 2");
+
+        // PROMOTION HAPPENS ON THE TARGET RANK
+        let mut board = Board::from_fen("4k3/P7/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+        board.set_promotion_setting(Color::White, ChessRole::Rook);
+        let outcome = board.move_piece_to(ChessSquare::new(0, 6), ChessSquare::new(0, 7)).unwrap();
+
+        assert_eq!(outcome.get_promotion(), Some(ChessRole::Rook));
+        assert_eq!(outcome.get_san(), "a8=R+");
+
+        assert_eq!(board.to_fen(), "R3k3/8/8/8/8/8/8/4K3 b - - 0 1");
+
+        // ONLY THE ACTIVE COLOR MAY MOVE
+        assert_eq!(board.move_piece_to(ChessSquare::new(0, 7), ChessSquare::new(0, 0)), Err(ChessError::NotYourTurn));
+    }
+
+    #[test]
+    fn play() {
+        let mut board = Board::from_fen("1r2k3/P7/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+        let outcome = board.play(Move::new(ChessSquare::new(0, 6), ChessSquare::new(1, 7), Some(ChessRole::Knight))).unwrap();
+
+        assert_eq!(outcome.get_promotion(), Some(ChessRole::Knight));
+        assert_eq!(outcome.get_san(), "axb8=N");
+        assert_eq!(board.to_fen(), "1N2k3/8/8/8/8/8/8/4K3 b - - 0 1");
+
+        // THE PROMOTION SETTING IS LEFT UNTOUCHED
+        assert_eq!(board.get_promotion_setting(Color::White), ChessRole::Queen);
+
+        let mut board = Board::from_fen("4k3/P7/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+
+        assert_eq!(board.play(Move::new(ChessSquare::new(0, 6), ChessSquare::new(0, 7), None)).unwrap().get_promotion(), Some(ChessRole::Queen));
+    }
+
+    #[test]
     fn move_piece_to() {
         let mut board = Board::new();
 
         let result = board.move_piece_to(ChessSquare::new(0, 1), ChessSquare::new(0, 2));
 
         assert!(result.is_ok());
-        assert!(result.unwrap().is_none());
+
+        let outcome = result.unwrap();
+
+        assert_eq!(outcome.get_captured(), None);
+        assert!(!outcome.is_castling() && !outcome.is_en_passent() && !outcome.is_check());
+        assert_eq!(outcome.get_san(), "a3");
+        assert_eq!(outcome.get_status(), GameStatus::Ongoing);
+
+        assert_eq!(board.move_piece_to(ChessSquare::new(0, 3), ChessSquare::new(0, 4)), Err(ChessError::NoPieceAt(ChessSquare::new(0, 3))));
+        assert_eq!(board.move_piece_to(ChessSquare::new(0, 6), ChessSquare::new(0, 3)), Err(ChessError::IllegalMove(Move::new(ChessSquare::new(0, 6), ChessSquare::new(0, 3), None))));
+
+        // CAPTURE AND MATE ARE TOLD APART
+        let mut board = Board::from_fen("6rk/5K2/8/8/8/8/8/6Q1 w - - 0 1").unwrap();
+        let outcome = board.move_piece_to(ChessSquare::new(6, 0), ChessSquare::new(6, 7)).unwrap();
+
+        assert_eq!(outcome.get_captured(), Some(ChessPiece::new(Color::Black, ChessRole::Rook)));
+        assert!(outcome.is_check() && outcome.is_checkmate());
+        assert_eq!(outcome.get_san(), "Qxg8#");
+        assert_eq!(outcome.get_status(), GameStatus::Checkmate(Color::White));
+    }
+
+    #[test]
+    fn perft() {
+        let positions = [
+            (fen::STARTING_POSITION, 4, 197281),
+            ("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1", 3, 97862),
+            ("8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1", 4, 43238),
+            ("r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1", 3, 9467),
+            ("rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8", 3, 62379),
+            ("r4rk1/1pp1qppp/p1np1n2/2b1p1B1/2B1P1b1/P1NP1N2/1PP1QPPP/R4RK1 w - - 0 10", 3, 89890),
+        ];
+
+        for &(position, depth, nodes) in positions.iter() {
+            assert_eq!(Board::from_fen(position).unwrap().perft(depth), nodes, "{}", position);
+        }
+
+        assert_eq!(Board::new().perft(0), 1);
+    }
+
+    #[test]
+    #[ignore]
+    fn perft_deep() {
+        let positions = [
+            (fen::STARTING_POSITION, 5, 4865609),
+            ("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1", 4, 4085603),
+            ("8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1", 5, 674624),
+            ("r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1", 4, 422333),
+            ("rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8", 4, 2103487),
+            ("r4rk1/1pp1qppp/p1np1n2/2b1p1B1/2B1P1b1/P1NP1N2/1PP1QPPP/R4RK1 w - - 0 10", 4, 3894594),
+        ];
+
+        for &(position, depth, nodes) in positions.iter() {
+            assert_eq!(Board::from_fen(position).unwrap().perft(depth), nodes, "{}", position);
+        }
+    }
+
+    #[test]
+    fn perft_divide() {
+        let board = Board::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1").unwrap();
+        let divide = board.perft_divide(2);
+
+        assert_eq!(divide.len(), 48);
+        assert_eq!(divide.iter().map(|(_, nodes)| nodes).sum::<u64>(), board.perft(2));
+
+        let castling = divide.iter().find(|(chess_move, _)| *chess_move == Move::new(ChessSquare::new(4, 0), ChessSquare::new(6, 0), None));
+
+        assert_eq!(castling.map(|(_, nodes)| *nodes), Some(43));
+        assert!(board.perft_divide(0).is_empty());
+    }
+
+    #[test]
+    fn make_move() {
+        // EN PASSENT CAPTURE
+        let mut board = Board::from_fen("rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3").unwrap();
+        let chess_move = Move::new(ChessSquare::new(4, 4), ChessSquare::new(5, 5), None);
+
+        let undo = board.make_move(chess_move);
+
+        assert_eq!(undo.get_captured(), Some(ChessPiece::new(Color::Black, ChessRole::Pawn)));
+        assert_eq!(board.to_fen(), "rnbqkbnr/ppp1p1pp/5P2/3p4/8/8/PPPP1PPP/RNBQKBNR b KQkq - 0 3");
+
+        board.unmake_move(chess_move, undo);
+
+        assert_eq!(board.to_fen(), "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3");
+
+        // CASTLING
+        let mut board = Board::from_fen("r3k2r/8/8/8/8/8/8/R3K2R b KQkq - 3 20").unwrap();
+        let chess_move = Move::new(ChessSquare::new(4, 7), ChessSquare::new(2, 7), None);
+
+        let undo = board.make_move(chess_move);
+
+        assert_eq!(board.to_fen(), "2kr3r/8/8/8/8/8/8/R3K2R w KQ - 4 21");
+
+        board.unmake_move(chess_move, undo);
+
+        assert_eq!(board.to_fen(), "r3k2r/8/8/8/8/8/8/R3K2R b KQkq - 3 20");
+
+        // PROMOTION WITH CAPTURE
+        let mut board = Board::from_fen("1r2k3/P7/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+        let chess_move = Move::new(ChessSquare::new(0, 6), ChessSquare::new(1, 7), Some(ChessRole::Knight));
+
+        let undo = board.make_move(chess_move);
+
+        assert_eq!(undo.get_captured(), Some(ChessPiece::new(Color::Black, ChessRole::Rook)));
+        assert_eq!(board.to_fen(), "1N2k3/8/8/8/8/8/8/4K3 b - - 0 1");
+
+        board.unmake_move(chess_move, undo);
+
+        assert_eq!(board.to_fen(), "1r2k3/P7/8/8/8/8/8/4K3 w - - 0 1");
+
+        // THE PROMOTION SETTING IS USED WHEN THE MOVE DOES NOT CHOOSE
+        board.set_promotion_setting(Color::White, ChessRole::Rook);
+
+        let chess_move = Move::new(ChessSquare::new(0, 6), ChessSquare::new(0, 7), None);
+        let undo = board.make_move(chess_move);
+
+        assert_eq!(board.to_fen(), "Rr2k3/8/8/8/8/8/8/4K3 b - - 0 1");
+
+        board.unmake_move(chess_move, undo);
+
+        assert_eq!(board.to_fen(), "1r2k3/P7/8/8/8/8/8/4K3 w - - 0 1");
+    }
+
+    #[test]
+    #[should_panic(expected = "Move e2e5 is not legal in this position.")]
+    fn make_move_err() {
+        let mut board = Board::new();
+
+        board.make_move(Move::new(ChessSquare::new(4, 1), ChessSquare::new(4, 4), None));
+    }
+
+    #[test]
+    fn unmake_move() {
+        let positions = [
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+            "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+            "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+        ];
+
+        for position in positions.iter() {
+            let mut board = Board::from_fen(position).unwrap();
+
+            unmake_walk(&mut board, 2);
+        }
+    }
+
+    #[test]
+    fn hash() {
+        // POLYGLOT REFERENCE KEYS
+        let keys = [
+            (fen::STARTING_POSITION, 0x463b_9618_1691_fc9c),
+            ("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1", 0x823c_9b50_fd11_4196),
+            ("rnbqkbnr/ppp1pppp/8/3p4/4P3/8/PPPP1PPP/RNBQKBNR w KQkq d6 0 2", 0x0756_b944_61c5_0fb0),
+            ("rnbqkbnr/ppp1pppp/8/3pP3/8/8/PPPP1PPP/RNBQKBNR b KQkq - 0 2", 0x662f_afb9_65db_29d4),
+            ("rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3", 0x22a4_8b5a_8e47_ff78),
+            ("rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPPKPPP/RNBQ1BNR b kq - 1 3", 0x652a_607c_a3f2_42c1),
+            ("rnbq1bnr/ppp1pkpp/8/3pPp2/8/8/PPPPKPPP/RNBQ1BNR w - - 2 4", 0x00fd_d303_c946_bdd9),
+            ("rnbqkbnr/p1pppppp/8/8/PpP4P/8/1P1PPPP1/RNBQKBNR b KQkq c3 0 3", 0x3c81_23ea_7b06_7637),
+            ("rnbqkbnr/p1pppppp/8/8/P6P/R1p5/1P1PPPP1/1NBQKBNR b Kkq - 1 4", 0x5c3f_9b82_9b27_9560),
+        ];
+
+        for &(position, key) in keys.iter() {
+            assert_eq!(Board::from_fen(position).unwrap().hash(), key, "{}", position);
+        }
+
+        // THE SAME KEYS ARE REACHED MOVE BY MOVE
+        let lines = [
+            (Board::new(), vec!["e4", "d5", "e5", "f5", "Ke2", "Kf7"], &keys[1..7]),
+            (Board::new(), vec!["a4", "b5", "h4", "b4", "c4"], &keys[7..8]),
+        ];
+
+        for (board, sans, expected) in lines.iter() {
+            let mut board = board.clone();
+            let mut hashes = Vec::new();
+
+            for san in sans.iter() {
+                let (from, to, promotion) = board.parse_san(san).unwrap();
+                board.play(Move::new(from, to, promotion)).unwrap();
+
+                hashes.push(board.hash());
+            }
+
+            let expected: Vec<u64> = expected.iter().map(|&(_, key)| key).collect();
+
+            assert_eq!(hashes[hashes.len() - expected.len()..].to_vec(), expected);
+        }
+
+        let mut board = Board::from_fen("rnbqkbnr/p1pppppp/8/8/PpP4P/8/1P1PPPP1/RNBQKBNR b KQkq c3 0 3").unwrap();
+
+        board.play(Move::new(ChessSquare::new(1, 3), ChessSquare::new(2, 2), None)).unwrap();
+        board.play(Move::new(ChessSquare::new(0, 0), ChessSquare::new(0, 2), None)).unwrap();
+
+        assert_eq!(board.hash(), 0x5c3f_9b82_9b27_9560);
+    }
+
+    #[test]
+    fn fen() {
+        let positions = [
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3",
+            "r3k2r/8/8/8/8/8/8/R3K2R b Kq - 12 40",
+            "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+        ];
+
+        for position in positions.iter() {
+            let board = Board::from_fen(position).unwrap();
+
+            assert_eq!(board.to_fen(), *position);
+        }
+
+        let board = Board::from_fen("r3k2r/8/8/8/8/8/8/R3K2R b Kq - 12 40").unwrap();
+
+        assert_eq!(board.get_active_color(), Color::Black);
+        assert_eq!(board.get_halfmove_clock(), 12);
+        assert_eq!(board.get_fullmove_number(), 40);
+        assert_eq!(board.get_castling_rights(), CastlingRights::new(true, false, false, true));
+
+        let board = Board::from_fen("rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3").unwrap();
+
+        assert_eq!(board.board.get_en_passent(), Some(5 * 8 + 5));
+        assert_eq!(Board::new().to_fen(), Board::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1").unwrap().to_fen());
+    }
+
+    #[test]
+    fn fen_err() {
+        assert_eq!(Board::from_fen("8/8/8/8/8/8/8/4K3 w - - 0 1").err(), Some(FenError::Position(vec![PositionProblem::KingCount(Color::Black, 0)])));
+        assert!(Board::from_fen_relaxed("8/8/8/8/8/8/8/4K3 w - - 0 1").is_ok());
+        assert_eq!(Board::from_fen("8/8/8/8/8/8/8/8 w").err(), Some(FenError::FieldCount(2)));
+        assert_eq!(Board::from_fen("4k3/8/8/8/8/8/8/4K3 w K - 0 1").err(), Some(FenError::Position(vec![PositionProblem::InvalidCastling(Color::White)])));
+        assert_eq!(Board::from_fen("4k3/8/8/8/8/8/8/R3K2R w Kk - 0 1").err(), Some(FenError::Position(vec![PositionProblem::InvalidCastling(Color::Black)])));
+        assert_eq!(Board::from_fen_relaxed("4k3/8/8/8/8/8/8/4K3 w K - 0 1").unwrap().get_castling_rights(), CastlingRights::new(true, false, false, false));
+    }
+
+    #[test]
+    fn fen_after_move() {
+        let mut board = Board::new();
+
+        board.move_piece_to(ChessSquare::new(6, 0), ChessSquare::new(5, 2)).unwrap();
+
+        assert_eq!(board.to_fen(), "rnbqkbnr/pppppppp/8/8/8/5N2/PPPPPPPP/RNBQKB1R b KQkq - 1 1");
+
+        board.move_piece_to(ChessSquare::new(6, 7), ChessSquare::new(5, 5)).unwrap();
+
+        assert_eq!(board.to_fen(), "rnbqkb1r/pppppppp/5n2/8/8/5N2/PPPPPPPP/RNBQKB1R w KQkq - 2 2");
+
+        // DOUBLE PUSHES MARK THE SQUARE PASSED OVER
+        board.move_piece_to(ChessSquare::new(4, 1), ChessSquare::new(4, 3)).unwrap();
+
+        assert_eq!(board.to_fen(), "rnbqkb1r/pppppppp/5n2/8/4P3/5N2/PPPP1PPP/RNBQKB1R b KQkq e3 0 2");
     }
 }
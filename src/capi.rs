@@ -0,0 +1,337 @@
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::panic;
+use std::panic::UnwindSafe;
+use std::ptr;
+
+use crate::board::Board;
+use crate::color::Color;
+use crate::comp::ChessRole;
+use crate::comp::ChessSquare;
+use crate::comp::Move;
+use crate::error::ChessError;
+
+/*
+ * C functions, only built with the capi feature:
+ * - struct ChessBoard (opaque)
+ * - struct ChessMove
+ * - chess_board_new, chess_board_free
+ * - chess_board_set_fen, chess_board_get_fen
+ * - chess_board_legal_moves, chess_board_make_move
+ * - chess_board_get_pieces, chess_board_active_color
+ */
+
+// ERROR CODES, EVERY FUNCTION RETURNING INT RETURNS ONE OF THESE
+pub const CHESS_OK: i32 = 0;
+pub const CHESS_ERR_NULL_POINTER: i32 = -1;
+pub const CHESS_ERR_INVALID_ARGUMENT: i32 = -2;
+pub const CHESS_ERR_INVALID_POSITION: i32 = -3;
+pub const CHESS_ERR_NO_PIECE: i32 = -4;
+pub const CHESS_ERR_NOT_YOUR_TURN: i32 = -5;
+pub const CHESS_ERR_ILLEGAL_MOVE: i32 = -6;
+pub const CHESS_ERR_BUFFER_TOO_SMALL: i32 = -7;
+pub const CHESS_ERR_INTERNAL: i32 = -8;
+
+// PIECE CODES ARE A ROLE, WITH CHESS_BLACK ADDED FOR BLACK PIECES
+pub const CHESS_EMPTY: u8 = 0;
+pub const CHESS_PAWN: u8 = 1;
+pub const CHESS_KNIGHT: u8 = 2;
+pub const CHESS_BISHOP: u8 = 3;
+pub const CHESS_ROOK: u8 = 4;
+pub const CHESS_QUEEN: u8 = 5;
+pub const CHESS_KING: u8 = 6;
+pub const CHESS_BLACK: u8 = 8;
+
+pub const CHESS_WHITE_TO_MOVE: i32 = 0;
+pub const CHESS_BLACK_TO_MOVE: i32 = 1;
+
+pub struct ChessBoard {
+    board: Board,
+}
+
+// SQUARES ARE INDICES RANK * 8 + FILE, PROMOTION IS A ROLE CODE OR CHESS_EMPTY
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct ChessMove {
+    pub from: u8,
+    pub to: u8,
+    pub promotion: u8,
+}
+
+fn error_code(err: &ChessError) -> i32 {
+    match err {
+        ChessError::NoPieceAt(_) => CHESS_ERR_NO_PIECE,
+        ChessError::NotYourTurn => CHESS_ERR_NOT_YOUR_TURN,
+        ChessError::IllegalMove(_) => CHESS_ERR_ILLEGAL_MOVE,
+        ChessError::InvalidPosition(_) => CHESS_ERR_INVALID_POSITION,
+        ChessError::InvalidSquare(_, _) | ChessError::InvalidSquareName(_) | ChessError::InvalidPromotion(_) | ChessError::InvalidSan(_) => CHESS_ERR_INVALID_ARGUMENT,
+    }
+}
+
+fn role_code(role: ChessRole) -> u8 {
+    match role {
+        ChessRole::Pawn => CHESS_PAWN,
+        ChessRole::Knight => CHESS_KNIGHT,
+        ChessRole::Bichop => CHESS_BISHOP,
+        ChessRole::Rook => CHESS_ROOK,
+        ChessRole::Queen => CHESS_QUEEN,
+        ChessRole::King => CHESS_KING,
+    }
+}
+
+fn code_role(code: u8) -> Option<ChessRole> {
+    match code {
+        CHESS_PAWN => Some(ChessRole::Pawn),
+        CHESS_KNIGHT => Some(ChessRole::Knight),
+        CHESS_BISHOP => Some(ChessRole::Bichop),
+        CHESS_ROOK => Some(ChessRole::Rook),
+        CHESS_QUEEN => Some(ChessRole::Queen),
+        CHESS_KING => Some(ChessRole::King),
+        _ => None,
+    }
+}
+
+fn square(index: u8) -> Result<ChessSquare, i32> {
+    ChessSquare::try_from_index(index).map_err(|_| CHESS_ERR_INVALID_ARGUMENT)
+}
+
+// PANICS MUST NOT UNWIND INTO C
+fn guard<F: FnOnce() -> Result<(), i32> + UnwindSafe>(f: F) -> i32 {
+    match panic::catch_unwind(f) {
+        Ok(Ok(())) => CHESS_OK,
+        Ok(Err(code)) => code,
+        Err(_) => CHESS_ERR_INTERNAL,
+    }
+}
+
+unsafe fn board_mut<'a>(board: *mut ChessBoard) -> Result<&'a mut Board, i32> {
+    board.as_mut().map(|board| &mut board.board).ok_or(CHESS_ERR_NULL_POINTER)
+}
+
+unsafe fn board_ref<'a>(board: *const ChessBoard) -> Result<&'a Board, i32> {
+    board.as_ref().map(|board| &board.board).ok_or(CHESS_ERR_NULL_POINTER)
+}
+
+// ---
+
+/// Creates a board in the starting position, free it with chess_board_free.
+#[no_mangle]
+pub extern "C" fn chess_board_new() -> *mut ChessBoard {
+    Box::into_raw(Box::new(ChessBoard { board: Board::new() }))
+}
+
+/// # Safety
+/// `board` must come from chess_board_new and not be used afterwards. Null is ignored.
+#[no_mangle]
+pub unsafe extern "C" fn chess_board_free(board: *mut ChessBoard) {
+    if !board.is_null() {
+        drop(Box::from_raw(board));
+    }
+}
+
+/// Replaces the position with a FEN string, the board is left as it was on errors.
+///
+/// # Safety
+/// `board` must be a live board and `fen` a null terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn chess_board_set_fen(board: *mut ChessBoard, fen: *const c_char) -> i32 {
+    guard(|| {
+        let board = board_mut(board)?;
+
+        if fen.is_null() {
+            return Err(CHESS_ERR_NULL_POINTER);
+        }
+
+        let fen = CStr::from_ptr(fen).to_str().map_err(|_| CHESS_ERR_INVALID_ARGUMENT)?;
+
+        *board = Board::from_fen(fen).map_err(|_| CHESS_ERR_INVALID_POSITION)?;
+
+        Ok(())
+    })
+}
+
+/// Writes the position as a null terminated FEN string into `buffer`.
+///
+/// # Safety
+/// `board` must be a live board and `buffer` must hold `length` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn chess_board_get_fen(board: *const ChessBoard, buffer: *mut c_char, length: usize) -> i32 {
+    guard(|| {
+        let fen = board_ref(board)?.to_fen();
+
+        if buffer.is_null() {
+            return Err(CHESS_ERR_NULL_POINTER);
+        }
+
+        if fen.len() + 1 > length {
+            return Err(CHESS_ERR_BUFFER_TOO_SMALL);
+        }
+
+        ptr::copy_nonoverlapping(fen.as_ptr() as *const c_char, buffer, fen.len());
+        *buffer.add(fen.len()) = 0;
+
+        Ok(())
+    })
+}
+
+/// Writes the legal moves of the piece on `square` into `moves` and their number into `count`.
+/// A square without a piece of the side to move has no moves. When `capacity` is too small,
+/// `count` still receives the number of moves needed.
+///
+/// # Safety
+/// `board` must be a live board, `moves` must hold `capacity` moves and `count` must be writable.
+#[no_mangle]
+pub unsafe extern "C" fn chess_board_legal_moves(board: *const ChessBoard, square: u8, moves: *mut ChessMove, capacity: usize, count: *mut usize) -> i32 {
+    guard(|| {
+        let board = board_ref(board)?;
+        let from = self::square(square)?;
+
+        if count.is_null() || (moves.is_null() && capacity > 0) {
+            return Err(CHESS_ERR_NULL_POINTER);
+        }
+
+        let legal: Vec<ChessMove> = board.legal_moves().into_iter()
+            .filter(|chess_move| chess_move.get_from() == from)
+            .map(|chess_move| {
+                let to = chess_move.get_to();
+
+                ChessMove { from: square, to: to.get_index(), promotion: chess_move.get_promotion().map_or(CHESS_EMPTY, role_code) }
+            })
+            .collect();
+
+        *count = legal.len();
+
+        if legal.len() > capacity {
+            return Err(CHESS_ERR_BUFFER_TOO_SMALL);
+        }
+
+        ptr::copy_nonoverlapping(legal.as_ptr(), moves, legal.len());
+
+        Ok(())
+    })
+}
+
+/// Plays a move for the side to move. Promotions without a promotion role use the promotion setting.
+///
+/// # Safety
+/// `board` must be a live board.
+#[no_mangle]
+pub unsafe extern "C" fn chess_board_make_move(board: *mut ChessBoard, chess_move: ChessMove) -> i32 {
+    guard(|| {
+        let board = board_mut(board)?;
+
+        let promotion = match chess_move.promotion {
+            CHESS_EMPTY => None,
+            code => Some(code_role(code).ok_or(CHESS_ERR_INVALID_ARGUMENT)?),
+        };
+
+        let chess_move = Move::try_new(square(chess_move.from)?, square(chess_move.to)?, promotion).map_err(|err| error_code(&err))?;
+
+        board.play(chess_move).map(|_| ()).map_err(|err| error_code(&err))
+    })
+}
+
+/// Writes 64 piece codes, one per square index, into `pieces`.
+///
+/// # Safety
+/// `board` must be a live board and `pieces` must hold 64 bytes.
+#[no_mangle]
+pub unsafe extern "C" fn chess_board_get_pieces(board: *const ChessBoard, pieces: *mut u8) -> i32 {
+    guard(|| {
+        let board = board_ref(board)?;
+
+        if pieces.is_null() {
+            return Err(CHESS_ERR_NULL_POINTER);
+        }
+
+        for (index, piece) in board.get_active_pieces().iter().enumerate() {
+            *pieces.add(index) = piece.map_or(CHESS_EMPTY, |piece| {
+                role_code(piece.get_role()) + if piece.get_color() == Color::Black { CHESS_BLACK } else { 0 }
+            });
+        }
+
+        Ok(())
+    })
+}
+
+/// Returns CHESS_WHITE_TO_MOVE or CHESS_BLACK_TO_MOVE, or an error code for a null board.
+///
+/// # Safety
+/// `board` must be a live board or null.
+#[no_mangle]
+pub unsafe extern "C" fn chess_board_active_color(board: *const ChessBoard) -> i32 {
+    match board_ref(board) {
+        Ok(board) => match board.get_active_color() {
+            Color::White => CHESS_WHITE_TO_MOVE,
+            Color::Black => CHESS_BLACK_TO_MOVE,
+        },
+        Err(code) => code,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+    use std::ptr;
+
+    use crate::capi::*;
+
+    #[test]
+    fn board() {
+        unsafe {
+            let board = chess_board_new();
+
+            let mut moves = [ChessMove { from: 0, to: 0, promotion: 0 }; 8];
+            let mut count = 0;
+
+            assert_eq!(chess_board_legal_moves(board, 12, moves.as_mut_ptr(), moves.len(), &mut count), CHESS_OK);
+            assert_eq!(&moves[..count], [ChessMove { from: 12, to: 20, promotion: 0 }, ChessMove { from: 12, to: 28, promotion: 0 }]);
+            assert_eq!(chess_board_legal_moves(board, 12, moves.as_mut_ptr(), 1, &mut count), CHESS_ERR_BUFFER_TOO_SMALL);
+            assert_eq!(count, 2);
+            assert_eq!(chess_board_legal_moves(board, 64, moves.as_mut_ptr(), moves.len(), &mut count), CHESS_ERR_INVALID_ARGUMENT);
+
+            assert_eq!(chess_board_make_move(board, ChessMove { from: 12, to: 28, promotion: 0 }), CHESS_OK);
+            assert_eq!(chess_board_make_move(board, ChessMove { from: 12, to: 28, promotion: 0 }), CHESS_ERR_NO_PIECE);
+            assert_eq!(chess_board_make_move(board, ChessMove { from: 6, to: 21, promotion: 0 }), CHESS_ERR_NOT_YOUR_TURN);
+            assert_eq!(chess_board_make_move(board, ChessMove { from: 52, to: 28, promotion: 0 }), CHESS_ERR_ILLEGAL_MOVE);
+            assert_eq!(chess_board_active_color(board), CHESS_BLACK_TO_MOVE);
+
+            let mut pieces = [0u8; 64];
+
+            assert_eq!(chess_board_get_pieces(board, pieces.as_mut_ptr()), CHESS_OK);
+            assert_eq!(pieces[4], CHESS_KING);
+            assert_eq!(pieces[28], CHESS_PAWN);
+            assert_eq!(pieces[59], CHESS_QUEEN + CHESS_BLACK);
+            assert_eq!(pieces[12], CHESS_EMPTY);
+
+            chess_board_free(board);
+        }
+    }
+
+    #[test]
+    fn fen() {
+        unsafe {
+            let board = chess_board_new();
+            let fen = CString::new("4k3/P7/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+
+            assert_eq!(chess_board_set_fen(board, fen.as_ptr()), CHESS_OK);
+            assert_eq!(chess_board_make_move(board, ChessMove { from: 48, to: 56, promotion: CHESS_KNIGHT }), CHESS_OK);
+
+            let mut buffer = [0 as c_char; 128];
+
+            assert_eq!(chess_board_get_fen(board, buffer.as_mut_ptr(), buffer.len()), CHESS_OK);
+            assert_eq!(CStr::from_ptr(buffer.as_ptr()).to_str(), Ok("N3k3/8/8/8/8/8/8/4K3 b - - 0 1"));
+            assert_eq!(chess_board_get_fen(board, buffer.as_mut_ptr(), 10), CHESS_ERR_BUFFER_TOO_SMALL);
+
+            let invalid = CString::new("8/8/8/8/8/8/8/8 w - - 0 1").unwrap();
+
+            assert_eq!(chess_board_set_fen(board, invalid.as_ptr()), CHESS_ERR_INVALID_POSITION);
+            assert_eq!(chess_board_set_fen(board, ptr::null()), CHESS_ERR_NULL_POINTER);
+            assert_eq!(chess_board_set_fen(ptr::null_mut(), fen.as_ptr()), CHESS_ERR_NULL_POINTER);
+            assert_eq!(chess_board_make_move(board, ChessMove { from: 4, to: 5, promotion: CHESS_KING }), CHESS_ERR_INVALID_ARGUMENT);
+
+            chess_board_free(board);
+            chess_board_free(ptr::null_mut());
+        }
+    }
+}
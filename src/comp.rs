@@ -1,13 +1,22 @@
+use std::convert::TryFrom;
 use std::fmt;
+use std::hash::Hash;
+use std::hash::Hasher;
 use std::mem;
+use std::str::FromStr;
 
 use crate::color::Color;
+use crate::error::ChessError;
 
 /* 
  * Public Declarations:
  * - struct ChessSquare
+ * - enum ChessFile
+ * - enum ChessRank
  * - struct ChessPiece
  * - enum ChessRole
+ * - struct Move
+ * - struct CastlingRights
  */
 
 #[derive(Copy, Clone)]
@@ -17,15 +26,87 @@ pub struct ChessSquare {
 }
 
 impl ChessSquare {
+    pub const A1: ChessSquare = ChessSquare { file: 0, rank: 0 };
+    pub const B1: ChessSquare = ChessSquare { file: 1, rank: 0 };
+    pub const C1: ChessSquare = ChessSquare { file: 2, rank: 0 };
+    pub const D1: ChessSquare = ChessSquare { file: 3, rank: 0 };
+    pub const E1: ChessSquare = ChessSquare { file: 4, rank: 0 };
+    pub const F1: ChessSquare = ChessSquare { file: 5, rank: 0 };
+    pub const G1: ChessSquare = ChessSquare { file: 6, rank: 0 };
+    pub const H1: ChessSquare = ChessSquare { file: 7, rank: 0 };
+    pub const A2: ChessSquare = ChessSquare { file: 0, rank: 1 };
+    pub const B2: ChessSquare = ChessSquare { file: 1, rank: 1 };
+    pub const C2: ChessSquare = ChessSquare { file: 2, rank: 1 };
+    pub const D2: ChessSquare = ChessSquare { file: 3, rank: 1 };
+    pub const E2: ChessSquare = ChessSquare { file: 4, rank: 1 };
+    pub const F2: ChessSquare = ChessSquare { file: 5, rank: 1 };
+    pub const G2: ChessSquare = ChessSquare { file: 6, rank: 1 };
+    pub const H2: ChessSquare = ChessSquare { file: 7, rank: 1 };
+    pub const A3: ChessSquare = ChessSquare { file: 0, rank: 2 };
+    pub const B3: ChessSquare = ChessSquare { file: 1, rank: 2 };
+    pub const C3: ChessSquare = ChessSquare { file: 2, rank: 2 };
+    pub const D3: ChessSquare = ChessSquare { file: 3, rank: 2 };
+    pub const E3: ChessSquare = ChessSquare { file: 4, rank: 2 };
+    pub const F3: ChessSquare = ChessSquare { file: 5, rank: 2 };
+    pub const G3: ChessSquare = ChessSquare { file: 6, rank: 2 };
+    pub const H3: ChessSquare = ChessSquare { file: 7, rank: 2 };
+    pub const A4: ChessSquare = ChessSquare { file: 0, rank: 3 };
+    pub const B4: ChessSquare = ChessSquare { file: 1, rank: 3 };
+    pub const C4: ChessSquare = ChessSquare { file: 2, rank: 3 };
+    pub const D4: ChessSquare = ChessSquare { file: 3, rank: 3 };
+    pub const E4: ChessSquare = ChessSquare { file: 4, rank: 3 };
+    pub const F4: ChessSquare = ChessSquare { file: 5, rank: 3 };
+    pub const G4: ChessSquare = ChessSquare { file: 6, rank: 3 };
+    pub const H4: ChessSquare = ChessSquare { file: 7, rank: 3 };
+    pub const A5: ChessSquare = ChessSquare { file: 0, rank: 4 };
+    pub const B5: ChessSquare = ChessSquare { file: 1, rank: 4 };
+    pub const C5: ChessSquare = ChessSquare { file: 2, rank: 4 };
+    pub const D5: ChessSquare = ChessSquare { file: 3, rank: 4 };
+    pub const E5: ChessSquare = ChessSquare { file: 4, rank: 4 };
+    pub const F5: ChessSquare = ChessSquare { file: 5, rank: 4 };
+    pub const G5: ChessSquare = ChessSquare { file: 6, rank: 4 };
+    pub const H5: ChessSquare = ChessSquare { file: 7, rank: 4 };
+    pub const A6: ChessSquare = ChessSquare { file: 0, rank: 5 };
+    pub const B6: ChessSquare = ChessSquare { file: 1, rank: 5 };
+    pub const C6: ChessSquare = ChessSquare { file: 2, rank: 5 };
+    pub const D6: ChessSquare = ChessSquare { file: 3, rank: 5 };
+    pub const E6: ChessSquare = ChessSquare { file: 4, rank: 5 };
+    pub const F6: ChessSquare = ChessSquare { file: 5, rank: 5 };
+    pub const G6: ChessSquare = ChessSquare { file: 6, rank: 5 };
+    pub const H6: ChessSquare = ChessSquare { file: 7, rank: 5 };
+    pub const A7: ChessSquare = ChessSquare { file: 0, rank: 6 };
+    pub const B7: ChessSquare = ChessSquare { file: 1, rank: 6 };
+    pub const C7: ChessSquare = ChessSquare { file: 2, rank: 6 };
+    pub const D7: ChessSquare = ChessSquare { file: 3, rank: 6 };
+    pub const E7: ChessSquare = ChessSquare { file: 4, rank: 6 };
+    pub const F7: ChessSquare = ChessSquare { file: 5, rank: 6 };
+    pub const G7: ChessSquare = ChessSquare { file: 6, rank: 6 };
+    pub const H7: ChessSquare = ChessSquare { file: 7, rank: 6 };
+    pub const A8: ChessSquare = ChessSquare { file: 0, rank: 7 };
+    pub const B8: ChessSquare = ChessSquare { file: 1, rank: 7 };
+    pub const C8: ChessSquare = ChessSquare { file: 2, rank: 7 };
+    pub const D8: ChessSquare = ChessSquare { file: 3, rank: 7 };
+    pub const E8: ChessSquare = ChessSquare { file: 4, rank: 7 };
+    pub const F8: ChessSquare = ChessSquare { file: 5, rank: 7 };
+    pub const G8: ChessSquare = ChessSquare { file: 6, rank: 7 };
+    pub const H8: ChessSquare = ChessSquare { file: 7, rank: 7 };
+
     pub fn new(file: u8, rank: u8) -> ChessSquare {
-        if file >= 8 || rank >= 8 {
-            panic!("Parameters must have values less than 8.");
+        match ChessSquare::try_new(file, rank) {
+            Ok(square) => square,
+            Err(_) => panic!("Parameters must have values less than 8."),
         }
+    }
 
-        ChessSquare {
-            file: file,
-            rank: rank
+    pub fn try_new(file: u8, rank: u8) -> Result<ChessSquare, ChessError> {
+        if file >= 8 || rank >= 8 {
+            return Err(ChessError::InvalidSquare(file, rank));
         }
+
+        Ok(ChessSquare {
+            file,
+            rank
+        })
     }
 
     pub fn get_file(&self) -> u8 {
@@ -36,8 +117,71 @@ impl ChessSquare {
         self.rank
     }
 
-    fn _format(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "ChessSquare [ file: {}, rank: {} ]", self.file, self.rank)
+    pub fn from_coords(file: ChessFile, rank: ChessRank) -> ChessSquare {
+        ChessSquare {
+            file: file.get_index(),
+            rank: rank.get_index()
+        }
+    }
+
+    // INDICES RUN rank * 8 + file FROM a1 = 0 TO h8 = 63
+    pub fn from_index(index: u8) -> ChessSquare {
+        match ChessSquare::try_from_index(index) {
+            Ok(square) => square,
+            Err(_) => panic!("Index must be less than 64."),
+        }
+    }
+
+    pub fn try_from_index(index: u8) -> Result<ChessSquare, ChessError> {
+        ChessSquare::try_new(index % 8, index / 8)
+    }
+
+    pub fn get_index(&self) -> u8 {
+        self.rank * 8 + self.file
+    }
+
+    pub fn get_chess_file(&self) -> ChessFile {
+        ChessFile::ALL[self.file as usize]
+    }
+
+    pub fn get_chess_rank(&self) -> ChessRank {
+        ChessRank::ALL[self.rank as usize]
+    }
+
+    // a1, b1, ..., h8 IN INDEX ORDER
+    pub fn all() -> impl Iterator<Item = ChessSquare> {
+        (0..64).map(ChessSquare::from_index)
+    }
+
+    // THE a1-h8 DIRECTION THROUGH THIS SQUARE, ITSELF INCLUDED
+    pub fn get_diagonal(&self) -> impl Iterator<Item = ChessSquare> {
+        let difference = self.file as i8 - self.rank as i8;
+
+        ChessSquare::all().filter(move |square| square.file as i8 - square.rank as i8 == difference)
+    }
+
+    // THE a8-h1 DIRECTION THROUGH THIS SQUARE, ITSELF INCLUDED
+    pub fn get_anti_diagonal(&self) -> impl Iterator<Item = ChessSquare> {
+        let sum = self.file + self.rank;
+
+        ChessSquare::all().filter(move |square| square.file + square.rank == sum)
+    }
+
+    // KING MOVES BETWEEN THE SQUARES
+    pub fn distance(&self, other: ChessSquare) -> u8 {
+        self.file_distance(other).max(self.rank_distance(other))
+    }
+
+    pub fn manhattan_distance(&self, other: ChessSquare) -> u8 {
+        self.file_distance(other) + self.rank_distance(other)
+    }
+
+    pub fn file_distance(&self, other: ChessSquare) -> u8 {
+        (self.file as i8 - other.file as i8).unsigned_abs()
+    }
+
+    pub fn rank_distance(&self, other: ChessSquare) -> u8 {
+        (self.rank as i8 - other.rank as i8).unsigned_abs()
     }
 }
 
@@ -47,20 +191,48 @@ impl PartialEq for ChessSquare {
     }
 }
 
+impl Eq for ChessSquare {}
+
 impl fmt::Display for ChessSquare {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self._format(f)
+        write!(f, "{}{}", self.get_chess_file(), self.get_chess_rank())
     }
 }
 impl fmt::Debug for ChessSquare {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self._format(f)
+        write!(f, "ChessSquare [ file: {}, rank: {} ]", self.file, self.rank)
+    }
+}
+
+impl FromStr for ChessSquare {
+    type Err = ChessError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        let mut chars = name.chars();
+
+        match (chars.next().and_then(ChessFile::from_char), chars.next().and_then(ChessRank::from_char), chars.next()) {
+            (Some(file), Some(rank), None) => Ok(ChessSquare::from_coords(file, rank)),
+            _ => Err(ChessError::InvalidSquareName(name.to_string())),
+        }
+    }
+}
+
+impl TryFrom<&str> for ChessSquare {
+    type Error = ChessError;
+
+    fn try_from(name: &str) -> Result<Self, Self::Error> {
+        name.parse()
     }
 }
 
 #[cfg(test)]
 mod chess_square_tests {
+    use std::convert::TryFrom;
+
+    use crate::comp::ChessFile;
+    use crate::comp::ChessRank;
     use crate::comp::ChessSquare;
+    use crate::error::ChessError;
 
     #[test]
     fn r#const() {
@@ -73,13 +245,172 @@ mod chess_square_tests {
     #[test]
     #[should_panic(expected = "Parameters must have values less than 8.")]
     fn const_err_file() {
-        let piece = ChessSquare::new(8, 0);
+        ChessSquare::new(8, 0);
     }
 
     #[test]
     #[should_panic(expected = "Parameters must have values less than 8.")]
     fn const_err_rank() {
-        let piece = ChessSquare::new(0, 8);
+        ChessSquare::new(0, 8);
+    }
+
+    #[test]
+    fn try_const() {
+        assert_eq!(ChessSquare::try_new(7, 7), Ok(ChessSquare::new(7, 7)));
+        assert_eq!(ChessSquare::try_new(8, 0), Err(ChessError::InvalidSquare(8, 0)));
+        assert_eq!(ChessSquare::try_new(0, 8), Err(ChessError::InvalidSquare(0, 8)));
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!("e4".parse::<ChessSquare>(), Ok(ChessSquare::E4));
+        assert_eq!(ChessSquare::try_from("h8"), Ok(ChessSquare::new(7, 7)));
+        assert_eq!(format!("{}", ChessSquare::new(4, 3)), "e4");
+        assert_eq!(format!("{}", ChessSquare::A1), "a1");
+
+        for name in ["", "e", "e9", "i4", "E4", "e44", "4e"].iter() {
+            assert_eq!(name.parse::<ChessSquare>(), Err(ChessError::InvalidSquareName(name.to_string())));
+        }
+    }
+
+    #[test]
+    fn indices() {
+        assert_eq!(ChessSquare::from_index(0), ChessSquare::A1);
+        assert_eq!(ChessSquare::from_index(28), ChessSquare::E4);
+        assert_eq!(ChessSquare::H8.get_index(), 63);
+        assert_eq!(ChessSquare::try_from_index(64), Err(ChessError::InvalidSquare(0, 8)));
+    }
+
+    #[test]
+    #[should_panic(expected = "Index must be less than 64.")]
+    fn from_index_err() {
+        ChessSquare::from_index(64);
+    }
+
+    #[test]
+    fn files_and_ranks() {
+        assert_eq!(ChessSquare::from_coords(ChessFile::E, ChessRank::Fourth), ChessSquare::E4);
+        assert_eq!(ChessSquare::E4.get_chess_file(), ChessFile::E);
+        assert_eq!(ChessSquare::E4.get_chess_rank(), ChessRank::Fourth);
+        assert_eq!(ChessFile::try_from_index(8), Err(ChessError::InvalidSquare(8, 0)));
+        assert_eq!(ChessRank::try_from_index(8), Err(ChessError::InvalidSquare(0, 8)));
+
+        assert_eq!(ChessFile::C.get_squares().collect::<Vec<_>>(), [ChessSquare::C1, ChessSquare::C2, ChessSquare::C3, ChessSquare::C4, ChessSquare::C5, ChessSquare::C6, ChessSquare::C7, ChessSquare::C8]);
+        assert_eq!(ChessRank::Second.get_squares().collect::<Vec<_>>(), [ChessSquare::A2, ChessSquare::B2, ChessSquare::C2, ChessSquare::D2, ChessSquare::E2, ChessSquare::F2, ChessSquare::G2, ChessSquare::H2]);
+    }
+
+    #[test]
+    fn iteration() {
+        let all: Vec<ChessSquare> = ChessSquare::all().collect();
+
+        assert_eq!(all.len(), 64);
+        assert!(all.iter().enumerate().all(|(index, square)| square.get_index() as usize == index));
+
+        assert_eq!(ChessSquare::C1.get_diagonal().collect::<Vec<_>>(), [ChessSquare::C1, ChessSquare::D2, ChessSquare::E3, ChessSquare::F4, ChessSquare::G5, ChessSquare::H6]);
+        assert_eq!(ChessSquare::C1.get_anti_diagonal().collect::<Vec<_>>(), [ChessSquare::C1, ChessSquare::B2, ChessSquare::A3]);
+        assert_eq!(ChessSquare::H8.get_anti_diagonal().collect::<Vec<_>>(), [ChessSquare::H8]);
+    }
+
+    #[test]
+    fn distances() {
+        assert_eq!(ChessSquare::A1.distance(ChessSquare::H8), 7);
+        assert_eq!(ChessSquare::E4.distance(ChessSquare::G5), 2);
+        assert_eq!(ChessSquare::E4.manhattan_distance(ChessSquare::G5), 3);
+        assert_eq!(ChessSquare::E4.file_distance(ChessSquare::B7), 3);
+        assert_eq!(ChessSquare::E4.rank_distance(ChessSquare::B7), 3);
+        assert_eq!(ChessSquare::D5.distance(ChessSquare::D5), 0);
+    }
+}
+
+// ---
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum ChessFile {
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    G,
+    H
+}
+
+impl ChessFile {
+    pub const ALL: [ChessFile; 8] = [ChessFile::A, ChessFile::B, ChessFile::C, ChessFile::D, ChessFile::E, ChessFile::F, ChessFile::G, ChessFile::H];
+
+    pub fn try_from_index(index: u8) -> Result<ChessFile, ChessError> {
+        ChessFile::ALL.get(index as usize).copied().ok_or(ChessError::InvalidSquare(index, 0))
+    }
+
+    pub fn get_index(&self) -> u8 {
+        *self as u8
+    }
+
+    fn from_char(c: char) -> Option<ChessFile> {
+        match c {
+            'a'..='h' => Some(ChessFile::ALL[(c as u8 - b'a') as usize]),
+            _ => None,
+        }
+    }
+
+    // FIRST TO EIGHTH RANK
+    pub fn get_squares(&self) -> impl Iterator<Item = ChessSquare> {
+        let file = self.get_index();
+
+        (0..8).map(move |rank| ChessSquare::new(file, rank))
+    }
+}
+
+impl fmt::Display for ChessFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", (b'a' + self.get_index()) as char)
+    }
+}
+
+// ---
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum ChessRank {
+    First,
+    Second,
+    Third,
+    Fourth,
+    Fifth,
+    Sixth,
+    Seventh,
+    Eighth
+}
+
+impl ChessRank {
+    pub const ALL: [ChessRank; 8] = [ChessRank::First, ChessRank::Second, ChessRank::Third, ChessRank::Fourth, ChessRank::Fifth, ChessRank::Sixth, ChessRank::Seventh, ChessRank::Eighth];
+
+    pub fn try_from_index(index: u8) -> Result<ChessRank, ChessError> {
+        ChessRank::ALL.get(index as usize).copied().ok_or(ChessError::InvalidSquare(0, index))
+    }
+
+    pub fn get_index(&self) -> u8 {
+        *self as u8
+    }
+
+    fn from_char(c: char) -> Option<ChessRank> {
+        match c {
+            '1'..='8' => Some(ChessRank::ALL[(c as u8 - b'1') as usize]),
+            _ => None,
+        }
+    }
+
+    // a TO h FILE
+    pub fn get_squares(&self) -> impl Iterator<Item = ChessSquare> {
+        let rank = self.get_index();
+
+        (0..8).map(move |file| ChessSquare::new(file, rank))
+    }
+}
+
+impl fmt::Display for ChessRank {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.get_index() + 1)
     }
 }
 
@@ -94,8 +425,8 @@ pub struct ChessPiece {
 impl ChessPiece {
     pub fn new(color: Color, role: ChessRole) -> ChessPiece {
         ChessPiece {
-            color: color,
-            role: role
+            color,
+            role
         }
     }
 
@@ -176,7 +507,364 @@ impl From<String> for ChessRole {
             "Bichop" => ChessRole::Bichop,
             "Knight" => ChessRole::Knight,
             "Rook" => ChessRole::Rook,
-            "Pawn" | _ => ChessRole::Pawn,
+            _ => ChessRole::Pawn,
+        }
+    }
+}
+// ---
+
+// FROM IN BITS 0-5, TO IN BITS 6-11 AND PROMOTION IN BITS 12-14 MAKE UP THE MOVE ITSELF
+const MOVE_MASK: u32 = 0x7fff;
+
+// DETAILS FILLED IN BY MOVE GENERATION: PIECE IN BITS 15-18, CAPTURED ROLE IN BITS 19-21 AND FLAGS FROM BIT 22
+pub(crate) const EN_PASSENT: u32 = 1 << 22;
+pub(crate) const CASTLING: u32 = 1 << 23;
+pub(crate) const DOUBLE_PUSH: u32 = 1 << 24;
+
+fn role_to_bits(role: ChessRole) -> u32 {
+    match role {
+        ChessRole::King => 1,
+        ChessRole::Queen => 2,
+        ChessRole::Bichop => 3,
+        ChessRole::Knight => 4,
+        ChessRole::Rook => 5,
+        ChessRole::Pawn => 6,
+    }
+}
+
+fn bits_to_role(bits: u32) -> Option<ChessRole> {
+    match bits {
+        1 => Some(ChessRole::King),
+        2 => Some(ChessRole::Queen),
+        3 => Some(ChessRole::Bichop),
+        4 => Some(ChessRole::Knight),
+        5 => Some(ChessRole::Rook),
+        6 => Some(ChessRole::Pawn),
+        _ => None,
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct Move {
+    data: u32
+}
+
+impl Move {
+    pub fn new(from: ChessSquare, to: ChessSquare, promotion: Option<ChessRole>) -> Move {
+        match Move::try_new(from, to, promotion) {
+            Ok(chess_move) => chess_move,
+            Err(_) => panic!("A pawn cannot get upgraded to a new pawn or a king!"),
         }
     }
-}
\ No newline at end of file
+
+    pub fn try_new(from: ChessSquare, to: ChessSquare, promotion: Option<ChessRole>) -> Result<Move, ChessError> {
+        let promotion_bits = match promotion {
+            None => 0,
+            Some(ChessRole::Queen) => 1,
+            Some(ChessRole::Bichop) => 2,
+            Some(ChessRole::Knight) => 3,
+            Some(ChessRole::Rook) => 4,
+            Some(role) => return Err(ChessError::InvalidPromotion(role)),
+        };
+
+        let from_bits = from.get_index() as u32;
+        let to_bits = to.get_index() as u32;
+
+        Ok(Move {
+            data: from_bits | to_bits << 6 | promotion_bits << 12
+        })
+    }
+
+    pub fn get_from(&self) -> ChessSquare {
+        let square = (self.data & 0x3f) as u8;
+
+        ChessSquare::from_index(square)
+    }
+
+    pub fn get_to(&self) -> ChessSquare {
+        let square = (self.data >> 6 & 0x3f) as u8;
+
+        ChessSquare::from_index(square)
+    }
+
+    pub fn get_promotion(&self) -> Option<ChessRole> {
+        match self.data >> 12 & 0x7 {
+            1 => Some(ChessRole::Queen),
+            2 => Some(ChessRole::Bichop),
+            3 => Some(ChessRole::Knight),
+            4 => Some(ChessRole::Rook),
+            _ => None,
+        }
+    }
+
+    // LONG ALGEBRAIC NOTATION AS UCI SPEAKS IT, LIKE e7e8q
+    pub fn to_uci(&self) -> String {
+        let promotion = match self.get_promotion() {
+            Some(ChessRole::Queen) => "q",
+            Some(ChessRole::Bichop) => "b",
+            Some(ChessRole::Knight) => "n",
+            Some(ChessRole::Rook) => "r",
+            _ => "",
+        };
+
+        format!("{}{}{}", self.get_from(), self.get_to(), promotion)
+    }
+
+    pub(crate) fn with_details(self, piece: ChessPiece, captured: Option<ChessRole>, flags: u32) -> Move {
+        let color_bit = match piece.get_color() {
+            Color::White => 0,
+            Color::Black => 1,
+        };
+
+        Move {
+            data: self.data & MOVE_MASK | role_to_bits(piece.get_role()) << 15 | color_bit << 18 | captured.map_or(0, role_to_bits) << 19 | flags
+        }
+    }
+
+    // ONLY MOVES FROM MOVE GENERATION KNOW THEIR PIECE
+    pub fn get_piece(&self) -> Option<ChessPiece> {
+        let color = if self.data >> 18 & 1 == 0 { Color::White } else { Color::Black };
+
+        bits_to_role(self.data >> 15 & 0x7).map(|role| ChessPiece::new(color, role))
+    }
+
+    pub fn get_captured(&self) -> Option<ChessRole> {
+        bits_to_role(self.data >> 19 & 0x7)
+    }
+
+    pub fn is_capture(&self) -> bool {
+        self.get_captured().is_some()
+    }
+
+    pub fn is_promotion(&self) -> bool {
+        self.get_promotion().is_some()
+    }
+
+    pub fn is_en_passent(&self) -> bool {
+        self.data & EN_PASSENT != 0
+    }
+
+    pub fn is_castling(&self) -> bool {
+        self.data & CASTLING != 0
+    }
+
+    pub fn is_double_push(&self) -> bool {
+        self.data & DOUBLE_PUSH != 0
+    }
+
+    fn _format(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Move [ from: {}, to: {}, promotion: {:?} ]", self.get_from(), self.get_to(), self.get_promotion())
+    }
+}
+
+// MOVES ARE EQUAL WHEN THEY MOVE THE SAME WAY, WHATEVER DETAILS THEY CARRY
+impl PartialEq for Move {
+    fn eq(&self, other: &Self) -> bool {
+        self.data & MOVE_MASK == other.data & MOVE_MASK
+    }
+}
+impl Eq for Move {}
+
+impl Hash for Move {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.data & MOVE_MASK).hash(state);
+    }
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self._format(f)
+    }
+}
+impl fmt::Debug for Move {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self._format(f)
+    }
+}
+
+#[cfg(test)]
+mod move_tests {
+    use crate::color::Color;
+    use crate::comp::CASTLING;
+    use crate::comp::ChessPiece;
+    use crate::comp::ChessRole;
+    use crate::comp::ChessSquare;
+    use crate::comp::DOUBLE_PUSH;
+    use crate::comp::EN_PASSENT;
+    use crate::comp::Move;
+    use crate::error::ChessError;
+
+    #[test]
+    fn r#const() {
+        let chess_move = Move::new(ChessSquare::new(4, 6), ChessSquare::new(3, 7), Some(ChessRole::Knight));
+
+        assert_eq!(chess_move.get_from(), ChessSquare::new(4, 6));
+        assert_eq!(chess_move.get_to(), ChessSquare::new(3, 7));
+        assert_eq!(chess_move.get_promotion(), Some(ChessRole::Knight));
+
+        let chess_move = Move::new(ChessSquare::new(7, 7), ChessSquare::new(0, 0), None);
+
+        assert_eq!(chess_move.get_from(), ChessSquare::new(7, 7));
+        assert_eq!(chess_move.get_to(), ChessSquare::new(0, 0));
+        assert_eq!(chess_move.get_promotion(), None);
+        assert_eq!(chess_move.get_piece(), None);
+        assert_eq!(chess_move.get_captured(), None);
+        assert_eq!(std::mem::size_of::<Move>(), 4);
+    }
+
+    #[test]
+    fn details() {
+        let chess_move = Move::new(ChessSquare::new(4, 4), ChessSquare::new(3, 5), None);
+        let detailed = chess_move.with_details(ChessPiece::new(Color::White, ChessRole::Pawn), Some(ChessRole::Pawn), EN_PASSENT);
+
+        assert_eq!(detailed.get_piece(), Some(ChessPiece::new(Color::White, ChessRole::Pawn)));
+        assert_eq!(detailed.get_captured(), Some(ChessRole::Pawn));
+        assert!(detailed.is_capture() && detailed.is_en_passent());
+        assert!(!detailed.is_castling() && !detailed.is_double_push() && !detailed.is_promotion());
+        assert_eq!(detailed, chess_move);
+
+        let detailed = Move::new(ChessSquare::new(6, 6), ChessSquare::new(6, 7), Some(ChessRole::Knight)).with_details(ChessPiece::new(Color::Black, ChessRole::King), None, CASTLING | DOUBLE_PUSH);
+
+        assert_eq!(detailed.get_piece(), Some(ChessPiece::new(Color::Black, ChessRole::King)));
+        assert_eq!(detailed.get_promotion(), Some(ChessRole::Knight));
+        assert!(detailed.is_castling() && detailed.is_double_push() && !detailed.is_capture());
+    }
+
+    #[test]
+    fn uci() {
+        assert_eq!(Move::new(ChessSquare::E2, ChessSquare::E4, None).to_uci(), "e2e4");
+        assert_eq!(Move::new(ChessSquare::E7, ChessSquare::D8, Some(ChessRole::Knight)).to_uci(), "e7d8n");
+        assert_eq!(Move::new(ChessSquare::A2, ChessSquare::A1, Some(ChessRole::Queen)).to_uci(), "a2a1q");
+    }
+
+    #[test]
+    #[should_panic(expected = "A pawn cannot get upgraded to a new pawn or a king!")]
+    fn const_err_promotion() {
+        Move::new(ChessSquare::new(4, 6), ChessSquare::new(4, 7), Some(ChessRole::King));
+    }
+
+    #[test]
+    fn try_const() {
+        assert!(Move::try_new(ChessSquare::new(4, 6), ChessSquare::new(4, 7), Some(ChessRole::Rook)).is_ok());
+        assert_eq!(Move::try_new(ChessSquare::new(4, 6), ChessSquare::new(4, 7), Some(ChessRole::Pawn)), Err(ChessError::InvalidPromotion(ChessRole::Pawn)));
+    }
+}
+
+// ---
+
+// WHITE KINGSIDE, WHITE QUEENSIDE, BLACK KINGSIDE AND BLACK QUEENSIDE IN BITS 0-3
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CastlingRights {
+    flags: u8
+}
+
+impl CastlingRights {
+    pub fn new(white_kingside: bool, white_queenside: bool, black_kingside: bool, black_queenside: bool) -> CastlingRights {
+        let mut rights = CastlingRights::none();
+
+        rights.set_kingside(Color::White, white_kingside);
+        rights.set_queenside(Color::White, white_queenside);
+        rights.set_kingside(Color::Black, black_kingside);
+        rights.set_queenside(Color::Black, black_queenside);
+
+        rights
+    }
+
+    pub fn none() -> CastlingRights {
+        CastlingRights {
+            flags: 0
+        }
+    }
+
+    pub fn all() -> CastlingRights {
+        CastlingRights {
+            flags: 0xf
+        }
+    }
+
+    fn flag(color: Color, kingside: bool) -> u8 {
+        match (color, kingside) {
+            (Color::White, true) => 1,
+            (Color::White, false) => 2,
+            (Color::Black, true) => 4,
+            (Color::Black, false) => 8,
+        }
+    }
+
+    pub fn is_kingside_allowed(&self, color: Color) -> bool {
+        self.flags & CastlingRights::flag(color, true) != 0
+    }
+
+    pub fn is_queenside_allowed(&self, color: Color) -> bool {
+        self.flags & CastlingRights::flag(color, false) != 0
+    }
+
+    pub fn set_kingside(&mut self, color: Color, allowed: bool) {
+        self.set(CastlingRights::flag(color, true), allowed);
+    }
+
+    pub fn set_queenside(&mut self, color: Color, allowed: bool) {
+        self.set(CastlingRights::flag(color, false), allowed);
+    }
+
+    fn set(&mut self, flag: u8, allowed: bool) {
+        if allowed {
+            self.flags |= flag;
+        } else {
+            self.flags &= !flag;
+        }
+    }
+
+    // INDICES 0-3 IN THE ORDER OF THE FLAGS, AS USED BY THE ZOBRIST KEYS
+    pub(crate) fn indices(&self) -> impl Iterator<Item = usize> {
+        let flags = self.flags;
+
+        (0..4).filter(move |index| flags & 1 << index != 0)
+    }
+
+    fn _format(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CastlingRights [ white_kingside: {}, white_queenside: {}, black_kingside: {}, black_queenside: {} ]", self.is_kingside_allowed(Color::White), self.is_queenside_allowed(Color::White), self.is_kingside_allowed(Color::Black), self.is_queenside_allowed(Color::Black))
+    }
+}
+
+impl fmt::Display for CastlingRights {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self._format(f)
+    }
+}
+impl fmt::Debug for CastlingRights {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self._format(f)
+    }
+}
+
+#[cfg(test)]
+mod castling_rights_tests {
+    use crate::color::Color;
+    use crate::comp::CastlingRights;
+
+    #[test]
+    fn r#const() {
+        let rights = CastlingRights::new(true, false, false, true);
+
+        assert!(rights.is_kingside_allowed(Color::White));
+        assert!(!rights.is_queenside_allowed(Color::White));
+        assert!(!rights.is_kingside_allowed(Color::Black));
+        assert!(rights.is_queenside_allowed(Color::Black));
+        assert_eq!(rights.indices().collect::<Vec<usize>>(), [0, 3]);
+
+        assert_eq!(CastlingRights::all(), CastlingRights::new(true, true, true, true));
+        assert_eq!(CastlingRights::none(), CastlingRights::new(false, false, false, false));
+    }
+
+    #[test]
+    fn sets() {
+        let mut rights = CastlingRights::none();
+
+        rights.set_queenside(Color::Black, true);
+        rights.set_kingside(Color::White, true);
+        rights.set_kingside(Color::White, false);
+
+        assert_eq!(rights, CastlingRights::new(false, false, false, true));
+    }
+}
@@ -1,18 +0,0 @@
-use std::mem;
-
-pub enum Direction {
-    Up,
-    UpLeft,
-    UpRight,
-    Down,
-    DownLeft,
-    DownRight,
-    Left,
-    Right,
-}
-
-impl PartialEq for Direction {
-    fn eq(&self, other: &Self) -> bool {
-        mem::discriminant(self) == mem::discriminant(other)
-    }
-}
\ No newline at end of file
@@ -0,0 +1,49 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::comp::ChessRole;
+use crate::comp::ChessSquare;
+use crate::comp::Move;
+use crate::san::SanError;
+use crate::validate;
+use crate::validate::PositionProblem;
+
+/*
+ * Public Declarations:
+ * - enum ChessError
+ */
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum ChessError {
+    NoPieceAt(ChessSquare),
+    NotYourTurn,
+    IllegalMove(Move),
+    InvalidSquare(u8, u8),
+    InvalidSquareName(String),
+    InvalidPromotion(ChessRole),
+    InvalidPosition(Vec<PositionProblem>),
+    InvalidSan(SanError),
+}
+
+impl fmt::Display for ChessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChessError::NoPieceAt(square) => write!(f, "No piece found on {}.", square),
+            ChessError::NotYourTurn => write!(f, "Piece on current position does not belong to the active color."),
+            ChessError::IllegalMove(chess_move) => write!(f, "Move {}{} is not legal in this position.", chess_move.get_from(), chess_move.get_to()),
+            ChessError::InvalidSquare(file, rank) => write!(f, "Square ({}, {}) is off the board, parameters must have values less than 8.", file, rank),
+            ChessError::InvalidSquareName(name) => write!(f, "Invalid square \"{}\".", name),
+            ChessError::InvalidPromotion(role) => write!(f, "A pawn cannot get upgraded to a {}.", role),
+            ChessError::InvalidPosition(problems) => write!(f, "Invalid position: {}", validate::format_problems(problems)),
+            ChessError::InvalidSan(err) => write!(f, "Invalid move: {}", err),
+        }
+    }
+}
+
+impl Error for ChessError {}
+
+impl From<SanError> for ChessError {
+    fn from(err: SanError) -> Self {
+        ChessError::InvalidSan(err)
+    }
+}
@@ -0,0 +1,398 @@
+use crate::bitboard;
+use crate::bitboard::Bitboards;
+use crate::board::Board;
+use crate::color::Color;
+use crate::role::Role;
+
+/*
+ * Public Declarations:
+ * - struct Weight
+ * - struct EvalParams
+ */
+
+// ONLY THESE ROLES COUNT TOWARDS MOBILITY AND KING ATTACKS
+const MOBILE_ROLES: [Role; 4] = [Role::Knight, Role::Bichop, Role::Rook, Role::Queen];
+
+const FILE_A: u64 = 0x0101_0101_0101_0101;
+
+// PIECE-SQUARE TABLES ARE WRITTEN FROM WHITE'S POINT OF VIEW WITH THE EIGHTH RANK ON TOP
+const PAWN_MG: [i32; 64] = [
+      0,   0,   0,   0,   0,   0,   0,   0,
+     50,  50,  50,  50,  50,  50,  50,  50,
+     10,  10,  20,  30,  30,  20,  10,  10,
+      5,   5,  10,  25,  25,  10,   5,   5,
+      0,   0,   0,  20,  20,   0,   0,   0,
+      5,  -5, -10,   0,   0, -10,  -5,   5,
+      5,  10,  10, -20, -20,  10,  10,   5,
+      0,   0,   0,   0,   0,   0,   0,   0,
+];
+
+const PAWN_EG: [i32; 64] = [
+      0,   0,   0,   0,   0,   0,   0,   0,
+     80,  80,  80,  80,  80,  80,  80,  80,
+     50,  50,  50,  50,  50,  50,  50,  50,
+     30,  30,  30,  30,  30,  30,  30,  30,
+     15,  15,  15,  15,  15,  15,  15,  15,
+      5,   5,   5,   5,   5,   5,   5,   5,
+      0,   0,   0,   0,   0,   0,   0,   0,
+      0,   0,   0,   0,   0,   0,   0,   0,
+];
+
+const KNIGHT: [i32; 64] = [
+    -50, -40, -30, -30, -30, -30, -40, -50,
+    -40, -20,   0,   0,   0,   0, -20, -40,
+    -30,   0,  10,  15,  15,  10,   0, -30,
+    -30,   5,  15,  20,  20,  15,   5, -30,
+    -30,   0,  15,  20,  20,  15,   0, -30,
+    -30,   5,  10,  15,  15,  10,   5, -30,
+    -40, -20,   0,   5,   5,   0, -20, -40,
+    -50, -40, -30, -30, -30, -30, -40, -50,
+];
+
+const BICHOP: [i32; 64] = [
+    -20, -10, -10, -10, -10, -10, -10, -20,
+    -10,   0,   0,   0,   0,   0,   0, -10,
+    -10,   0,   5,  10,  10,   5,   0, -10,
+    -10,   5,   5,  10,  10,   5,   5, -10,
+    -10,   0,  10,  10,  10,  10,   0, -10,
+    -10,  10,  10,  10,  10,  10,  10, -10,
+    -10,   5,   0,   0,   0,   0,   5, -10,
+    -20, -10, -10, -10, -10, -10, -10, -20,
+];
+
+const ROOK: [i32; 64] = [
+      0,   0,   0,   0,   0,   0,   0,   0,
+      5,  10,  10,  10,  10,  10,  10,   5,
+     -5,   0,   0,   0,   0,   0,   0,  -5,
+     -5,   0,   0,   0,   0,   0,   0,  -5,
+     -5,   0,   0,   0,   0,   0,   0,  -5,
+     -5,   0,   0,   0,   0,   0,   0,  -5,
+     -5,   0,   0,   0,   0,   0,   0,  -5,
+      0,   0,   0,   5,   5,   0,   0,   0,
+];
+
+const QUEEN: [i32; 64] = [
+    -20, -10, -10,  -5,  -5, -10, -10, -20,
+    -10,   0,   0,   0,   0,   0,   0, -10,
+    -10,   0,   5,   5,   5,   5,   0, -10,
+     -5,   0,   5,   5,   5,   5,   0,  -5,
+      0,   0,   5,   5,   5,   5,   0,  -5,
+    -10,   5,   5,   5,   5,   5,   0, -10,
+    -10,   0,   5,   0,   0,   0,   0, -10,
+    -20, -10, -10,  -5,  -5, -10, -10, -20,
+];
+
+const KING_MG: [i32; 64] = [
+    -30, -40, -40, -50, -50, -40, -40, -30,
+    -30, -40, -40, -50, -50, -40, -40, -30,
+    -30, -40, -40, -50, -50, -40, -40, -30,
+    -30, -40, -40, -50, -50, -40, -40, -30,
+    -20, -30, -30, -40, -40, -30, -30, -20,
+    -10, -20, -20, -20, -20, -20, -20, -10,
+     20,  20,   0,   0,   0,   0,  20,  20,
+     20,  30,  10,   0,   0,  10,  30,  20,
+];
+
+const KING_EG: [i32; 64] = [
+    -50, -40, -30, -20, -20, -30, -40, -50,
+    -30, -20, -10,   0,   0, -10, -20, -30,
+    -30, -10,  20,  30,  30,  20, -10, -30,
+    -30, -10,  30,  40,  40,  30, -10, -30,
+    -30, -10,  30,  40,  40,  30, -10, -30,
+    -30, -10,  20,  30,  30,  20, -10, -30,
+    -30, -30,   0,   0,   0,   0, -30, -30,
+    -50, -30, -30, -30, -30, -30, -30, -50,
+];
+
+#[derive(Copy, Clone, PartialEq, Eq, Default, Debug)]
+pub struct Weight {
+    pub mg: i32,
+    pub eg: i32,
+}
+
+impl Weight {
+    pub const fn new(mg: i32, eg: i32) -> Weight {
+        Weight {
+            mg,
+            eg
+        }
+    }
+}
+
+// ---
+
+#[derive(Clone, Debug)]
+pub struct EvalParams {
+    // INDEXED BY ROLE IN THE ORDER PAWN, KNIGHT, BICHOP, ROOK, QUEEN, KING
+    pub material: [Weight; 6],
+    pub psqt_mg: [[i32; 64]; 6],
+    pub psqt_eg: [[i32; 64]; 6],
+    pub phase: [i32; 6],
+
+    pub doubled_pawn: Weight,
+    pub isolated_pawn: Weight,
+    // INDEXED BY RANK AS SEEN FROM THE PAWN'S OWN SIDE
+    pub passed_pawn: [Weight; 8],
+
+    // PER SQUARE A PIECE CAN REACH
+    pub mobility: [Weight; 6],
+
+    // PER PAWN IN FRONT OF THE KING AND PER ATTACK ON THE SQUARES AROUND IT
+    pub pawn_shield: Weight,
+    pub king_attack: Weight,
+}
+
+impl Default for EvalParams {
+    fn default() -> Self {
+        EvalParams {
+            material: [Weight::new(100, 120), Weight::new(320, 300), Weight::new(330, 320), Weight::new(500, 530), Weight::new(900, 950), Weight::new(0, 0)],
+            psqt_mg: [PAWN_MG, KNIGHT, BICHOP, ROOK, QUEEN, KING_MG],
+            psqt_eg: [PAWN_EG, KNIGHT, BICHOP, ROOK, QUEEN, KING_EG],
+            phase: [0, 1, 1, 2, 4, 0],
+
+            doubled_pawn: Weight::new(-10, -20),
+            isolated_pawn: Weight::new(-15, -15),
+            passed_pawn: [
+                Weight::new(0, 0), Weight::new(5, 10), Weight::new(5, 15), Weight::new(10, 25),
+                Weight::new(20, 45), Weight::new(35, 75), Weight::new(60, 120), Weight::new(0, 0),
+            ],
+
+            mobility: [Weight::new(0, 0), Weight::new(4, 4), Weight::new(4, 5), Weight::new(2, 4), Weight::new(1, 2), Weight::new(0, 0)],
+
+            pawn_shield: Weight::new(12, 0),
+            king_attack: Weight::new(-8, -2),
+        }
+    }
+}
+
+// ---
+
+fn role_index(role: Role) -> usize {
+    match role {
+        Role::Pawn => 0,
+        Role::Knight => 1,
+        Role::Bichop => 2,
+        Role::Rook => 3,
+        Role::Queen => 4,
+        Role::King => 5,
+    }
+}
+
+fn relative_rank(color: Color, rank: u8) -> u8 {
+    match color {
+        Color::White => rank,
+        Color::Black => 7 - rank,
+    }
+}
+
+fn table_index(color: Color, square: u8) -> usize {
+    ((7 - relative_rank(color, square / 8)) * 8 + square % 8) as usize
+}
+
+fn file_mask(file: u8) -> u64 {
+    FILE_A << file
+}
+
+fn neighbour_files(file: u8) -> u64 {
+    let left = if file > 0 { file_mask(file - 1) } else { 0 };
+    let right = if file < 7 { file_mask(file + 1) } else { 0 };
+
+    left | right
+}
+
+// EVERY SQUARE ON THE RANKS IN FRONT OF THE GIVEN ONE AS SEEN FROM COLOR
+fn ahead_of(color: Color, rank: u8) -> u64 {
+    match color {
+        Color::White if rank < 7 => !0 << ((rank + 1) * 8),
+        Color::Black => (1 << (rank * 8)) - 1,
+        _ => 0,
+    }
+}
+
+fn pawn_structure(params: &EvalParams, board: &Bitboards, color: Color) -> Weight {
+    let own = board.get_pieces(color, Role::Pawn);
+    let enemy = board.get_pieces(color.opposite(), Role::Pawn);
+
+    let mut score = Weight::default();
+
+    for file in 0..8 {
+        let on_file = (own & file_mask(file)).count_ones() as i32;
+
+        if on_file > 1 {
+            score.mg += params.doubled_pawn.mg * (on_file - 1);
+            score.eg += params.doubled_pawn.eg * (on_file - 1);
+        }
+
+        if own & neighbour_files(file) == 0 {
+            score.mg += params.isolated_pawn.mg * on_file;
+            score.eg += params.isolated_pawn.eg * on_file;
+        }
+    }
+
+    for square in bitboard::squares(own) {
+        let (file, rank) = (square % 8, square / 8);
+        let front = ahead_of(color, rank) & (file_mask(file) | neighbour_files(file));
+
+        if enemy & front == 0 {
+            let bonus = params.passed_pawn[relative_rank(color, rank) as usize];
+
+            score.mg += bonus.mg;
+            score.eg += bonus.eg;
+        }
+    }
+
+    score
+}
+
+fn king_safety(params: &EvalParams, board: &Bitboards, color: Color) -> Weight {
+    let king = match board.get_king(color) {
+        Some(king) => king,
+        None => return Weight::default(),
+    };
+
+    let (file, rank) = (king % 8, king / 8);
+    let mut score = Weight::default();
+
+    // PAWNS ON THE TWO RANKS IN FRONT OF A KING THAT STAYED HOME
+    if relative_rank(color, rank) <= 1 {
+        let two_ranks_ahead = ahead_of(color, rank) & !ahead_of(color, relative_rank(color, relative_rank(color, rank) + 2));
+        let shield = board.get_pieces(color, Role::Pawn) & two_ranks_ahead & (file_mask(file) | neighbour_files(file));
+
+        score.mg += params.pawn_shield.mg * shield.count_ones() as i32;
+        score.eg += params.pawn_shield.eg * shield.count_ones() as i32;
+    }
+
+    let zone = bitboard::king_attacks(king) | bitboard::bit(king);
+    let enemy = color.opposite();
+    let occupied = board.get_occupied();
+
+    let attacks_on_zone: u32 = MOBILE_ROLES.iter()
+        .flat_map(|&role| bitboard::squares(board.get_pieces(enemy, role)).map(move |square| (role, square)))
+        .map(|(role, square)| (role.get_attacks(square, enemy, occupied) & zone).count_ones())
+        .sum();
+
+    score.mg += params.king_attack.mg * attacks_on_zone as i32;
+    score.eg += params.king_attack.eg * attacks_on_zone as i32;
+
+    score
+}
+
+fn side(params: &EvalParams, board: &Bitboards, color: Color) -> (Weight, i32) {
+    let own = board.get_color(color);
+    let occupied = board.get_occupied();
+
+    let mut score = Weight::default();
+    let mut phase = 0;
+
+    for square in bitboard::squares(own) {
+        let role = match board.get_piece(square) {
+            Some((_, role)) => role,
+            None => continue,
+        };
+
+        let index = role_index(role);
+        let table_square = table_index(color, square);
+
+        score.mg += params.material[index].mg + params.psqt_mg[index][table_square];
+        score.eg += params.material[index].eg + params.psqt_eg[index][table_square];
+        phase += params.phase[index];
+
+        if MOBILE_ROLES.contains(&role) {
+            let mobility = (role.get_attacks(square, color, occupied) & !own).count_ones() as i32;
+
+            score.mg += params.mobility[index].mg * mobility;
+            score.eg += params.mobility[index].eg * mobility;
+        }
+    }
+
+    for term in [pawn_structure(params, board, color), king_safety(params, board, color)].iter() {
+        score.mg += term.mg;
+        score.eg += term.eg;
+    }
+
+    (score, phase)
+}
+
+pub(crate) fn evaluate(board: &Board, params: &EvalParams) -> i32 {
+    let bitboards = board.get_bitboards();
+
+    let (white, white_phase) = side(params, bitboards, Color::White);
+    let (black, black_phase) = side(params, bitboards, Color::Black);
+
+    // TAPER BETWEEN MIDDLEGAME AND ENDGAME BY THE MATERIAL LEFT ON THE BOARD
+    let max_phase = (params.phase[1] * 4 + params.phase[2] * 4 + params.phase[3] * 4 + params.phase[4] * 2).max(1);
+    let phase = (white_phase + black_phase).min(max_phase);
+
+    let mg = white.mg - black.mg;
+    let eg = white.eg - black.eg;
+    let score = (mg * phase + eg * (max_phase - phase)) / max_phase;
+
+    match board.get_active_color() {
+        Color::White => score,
+        Color::Black => -score,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bitboard::Bitboards;
+    use crate::board::Board;
+    use crate::color::Color;
+    use crate::eval::EvalParams;
+    use crate::eval::Weight;
+    use crate::eval::pawn_structure;
+
+    fn bitboards(fen: &str) -> Bitboards {
+        *Board::from_fen(fen).unwrap().get_bitboards()
+    }
+
+    #[test]
+    fn symmetric() {
+        let params = EvalParams::default();
+
+        assert_eq!(Board::new().evaluate(&params), 0);
+
+        let white = Board::from_fen("r1bqkb1r/pppp1ppp/2n2n2/4p3/2B1P3/5N2/PPPP1PPP/RNBQK2R w KQkq - 4 4").unwrap();
+        let black = Board::from_fen("rnbqk2r/pppp1ppp/5n2/2b1p3/4P3/2N2N2/PPPP1PPP/R1BQKB1R b KQkq - 4 4").unwrap();
+
+        assert_eq!(white.evaluate(&params), black.evaluate(&params));
+    }
+
+    #[test]
+    fn side_to_move() {
+        let params = EvalParams::default();
+
+        let white = Board::from_fen("4k3/8/8/8/8/8/8/3QK3 w - - 0 1").unwrap();
+        let black = Board::from_fen("4k3/8/8/8/8/8/8/3QK3 b - - 0 1").unwrap();
+
+        assert!(white.evaluate(&params) > 800);
+        assert_eq!(white.evaluate(&params), -black.evaluate(&params));
+    }
+
+    #[test]
+    fn pawns() {
+        let params = EvalParams::default();
+
+        // DOUBLED AND ISOLATED ON THE A-FILE, PASSED ON THE H-FILE
+        let score = pawn_structure(&params, &bitboards("4k3/8/7P/8/P7/P7/8/4K3 w - - 0 1"), Color::White);
+        let expected_mg = params.doubled_pawn.mg + params.isolated_pawn.mg * 3 + params.passed_pawn[3].mg + params.passed_pawn[2].mg + params.passed_pawn[5].mg;
+
+        assert_eq!(score.mg, expected_mg);
+
+        // A PAWN ON AN ADJACENT FILE IN FRONT STOPS A PASSER
+        let score = pawn_structure(&params, &bitboards("4k3/1p6/8/8/P7/8/8/4K3 w - - 0 1"), Color::White);
+
+        assert_eq!(score, Weight::new(params.isolated_pawn.mg, params.isolated_pawn.eg));
+    }
+
+    #[test]
+    fn tunable() {
+        let board = Board::from_fen("4k3/8/8/8/8/8/8/3QK3 w - - 0 1").unwrap();
+        let mut params = EvalParams::default();
+
+        let before = board.evaluate(&params);
+        params.material[4] = Weight::new(params.material[4].mg + 100, params.material[4].eg + 100);
+
+        assert_eq!(board.evaluate(&params), before + 100);
+    }
+}
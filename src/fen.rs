@@ -0,0 +1,333 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::color::Color;
+use crate::comp::CastlingRights;
+use crate::comp::ChessPiece;
+use crate::comp::ChessRole;
+use crate::comp::ChessSquare;
+use crate::validate;
+use crate::validate::PositionProblem;
+
+/*
+ * Public Declarations:
+ * - enum FenError
+ */
+
+pub const STARTING_POSITION: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum FenError {
+    FieldCount(usize),
+    PiecePlacement(String),
+    ActiveColor(String),
+    Castling(String),
+    EnPassant(String),
+    HalfmoveClock(String),
+    FullmoveNumber(String),
+    Position(Vec<PositionProblem>),
+}
+
+impl fmt::Display for FenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FenError::FieldCount(count) => write!(f, "FEN must have 6 fields, found {}.", count),
+            FenError::PiecePlacement(field) => write!(f, "Invalid piece placement field \"{}\".", field),
+            FenError::ActiveColor(field) => write!(f, "Invalid active color field \"{}\".", field),
+            FenError::Castling(field) => write!(f, "Invalid castling field \"{}\".", field),
+            FenError::EnPassant(field) => write!(f, "Invalid en passant field \"{}\".", field),
+            FenError::HalfmoveClock(field) => write!(f, "Invalid halfmove clock field \"{}\".", field),
+            FenError::FullmoveNumber(field) => write!(f, "Invalid fullmove number field \"{}\".", field),
+            FenError::Position(problems) => write!(f, "Invalid position: {}", validate::format_problems(problems)),
+        }
+    }
+}
+
+impl Error for FenError {}
+
+// ---
+
+pub(crate) struct Fen {
+    pub pieces: Vec<Option<ChessPiece>>,
+    pub active_color: Color,
+    pub castling: CastlingRights,
+    pub en_passant: Option<ChessSquare>,
+    pub halfmove_clock: u32,
+    pub fullmove_number: u32,
+}
+
+pub(crate) fn piece_to_char(piece: ChessPiece) -> char {
+    let c = match piece.get_role() {
+        ChessRole::King => 'k',
+        ChessRole::Queen => 'q',
+        ChessRole::Bichop => 'b',
+        ChessRole::Knight => 'n',
+        ChessRole::Rook => 'r',
+        ChessRole::Pawn => 'p',
+    };
+
+    match piece.get_color() {
+        Color::White => c.to_ascii_uppercase(),
+        Color::Black => c,
+    }
+}
+
+pub(crate) fn char_to_piece(c: char) -> Option<ChessPiece> {
+    let role = match c.to_ascii_lowercase() {
+        'k' => ChessRole::King,
+        'q' => ChessRole::Queen,
+        'b' => ChessRole::Bichop,
+        'n' => ChessRole::Knight,
+        'r' => ChessRole::Rook,
+        'p' => ChessRole::Pawn,
+        _ => return None,
+    };
+
+    let color = if c.is_ascii_uppercase() { Color::White } else { Color::Black };
+
+    Some(ChessPiece::new(color, role))
+}
+
+fn parse_placement(field: &str) -> Result<Vec<Option<ChessPiece>>, FenError> {
+    let err = || FenError::PiecePlacement(field.to_string());
+
+    let rows: Vec<&str> = field.split('/').collect();
+    if rows.len() != 8 {
+        return Err(err());
+    }
+
+    let mut pieces: Vec<Option<ChessPiece>> = vec![None; 64];
+
+    // FEN LISTS RANK 8 FIRST
+    for (i, row) in rows.iter().enumerate() {
+        let rank = 7 - i;
+        let mut file = 0;
+
+        for c in row.chars() {
+            if let Some(empty) = c.to_digit(10) {
+                if empty == 0 || empty > 8 {
+                    return Err(err());
+                }
+
+                file += empty as usize;
+            } else {
+                let piece = char_to_piece(c).ok_or_else(err)?;
+
+                if file >= 8 {
+                    return Err(err());
+                }
+
+                pieces[rank * 8 + file] = Some(piece);
+                file += 1;
+            }
+
+            if file > 8 {
+                return Err(err());
+            }
+        }
+
+        if file != 8 {
+            return Err(err());
+        }
+    }
+
+    Ok(pieces)
+}
+
+pub(crate) fn parse_castling(field: &str) -> Result<CastlingRights, FenError> {
+    let mut castling = CastlingRights::none();
+
+    if field == "-" {
+        return Ok(castling);
+    }
+
+    if field.is_empty() {
+        return Err(FenError::Castling(field.to_string()));
+    }
+
+    for c in field.chars() {
+        let (color, kingside) = match c {
+            'K' => (Color::White, true),
+            'Q' => (Color::White, false),
+            'k' => (Color::Black, true),
+            'q' => (Color::Black, false),
+            _ => return Err(FenError::Castling(field.to_string())),
+        };
+
+        let allowed = if kingside { castling.is_kingside_allowed(color) } else { castling.is_queenside_allowed(color) };
+
+        if allowed {
+            return Err(FenError::Castling(field.to_string()));
+        }
+
+        if kingside {
+            castling.set_kingside(color, true);
+        } else {
+            castling.set_queenside(color, true);
+        }
+    }
+
+    Ok(castling)
+}
+
+pub(crate) fn castling_to_string(castling: CastlingRights) -> String {
+    let mut field = String::new();
+    if castling.is_kingside_allowed(Color::White) {
+        field.push('K');
+    }
+    if castling.is_queenside_allowed(Color::White) {
+        field.push('Q');
+    }
+    if castling.is_kingside_allowed(Color::Black) {
+        field.push('k');
+    }
+    if castling.is_queenside_allowed(Color::Black) {
+        field.push('q');
+    }
+    if field.is_empty() {
+        field.push('-');
+    }
+
+    field
+}
+
+pub(crate) fn parse(fen: &str) -> Result<Fen, FenError> {
+    let fields: Vec<&str> = fen.split_whitespace().collect();
+
+    if fields.len() != 6 {
+        return Err(FenError::FieldCount(fields.len()));
+    }
+
+    let pieces = parse_placement(fields[0])?;
+
+    let active_color = match fields[1] {
+        "w" => Color::White,
+        "b" => Color::Black,
+        _ => return Err(FenError::ActiveColor(fields[1].to_string())),
+    };
+
+    let castling = parse_castling(fields[2])?;
+
+    let en_passant = match fields[3] {
+        "-" => None,
+        _ => {
+            let square = fields[3].parse::<ChessSquare>().map_err(|_| FenError::EnPassant(fields[3].to_string()))?;
+
+            // THE TARGET SQUARE IS BEHIND A PAWN THAT JUST MOVED TWO STEPS
+            let expected_rank = match active_color {
+                Color::White => 5,
+                Color::Black => 2,
+            };
+
+            if square.get_rank() != expected_rank {
+                return Err(FenError::EnPassant(fields[3].to_string()));
+            }
+
+            Some(square)
+        }
+    };
+
+    let halfmove_clock = fields[4].parse::<u32>().map_err(|_| FenError::HalfmoveClock(fields[4].to_string()))?;
+
+    let fullmove_number = match fields[5].parse::<u32>() {
+        Ok(number) if number > 0 => number,
+        _ => return Err(FenError::FullmoveNumber(fields[5].to_string())),
+    };
+
+    Ok(Fen {
+        pieces,
+        active_color,
+        castling,
+        en_passant,
+        halfmove_clock,
+        fullmove_number,
+    })
+}
+
+pub(crate) fn format(fen: &Fen) -> String {
+    let mut placement = String::new();
+
+    for rank in (0..8).rev() {
+        let mut empty = 0;
+
+        for file in 0..8 {
+            match fen.pieces[rank * 8 + file] {
+                Some(piece) => {
+                    if empty > 0 {
+                        placement.push_str(&empty.to_string());
+                        empty = 0;
+                    }
+
+                    placement.push(piece_to_char(piece));
+                },
+                None => empty += 1,
+            }
+        }
+
+        if empty > 0 {
+            placement.push_str(&empty.to_string());
+        }
+
+        if rank > 0 {
+            placement.push('/');
+        }
+    }
+
+    let active_color = match fen.active_color {
+        Color::White => "w",
+        Color::Black => "b",
+    };
+
+    let castling = castling_to_string(fen.castling);
+
+    let en_passant = match fen.en_passant {
+        Some(square) => square.to_string(),
+        None => "-".to_string(),
+    };
+
+    format!("{} {} {} {} {} {}", placement, active_color, castling, en_passant, fen.halfmove_clock, fen.fullmove_number)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fen;
+    use crate::fen::FenError;
+    use crate::color::Color;
+    use crate::comp::ChessPiece;
+    use crate::comp::ChessRole;
+
+    #[test]
+    fn parse() {
+        let parsed = fen::parse(fen::STARTING_POSITION).unwrap();
+
+        assert_eq!(parsed.pieces[0], Some(ChessPiece::new(Color::White, ChessRole::Rook)));
+        assert_eq!(parsed.pieces[60], Some(ChessPiece::new(Color::Black, ChessRole::King)));
+        assert_eq!(parsed.pieces[32], None);
+        assert_eq!(parsed.active_color, Color::White);
+        assert!(parsed.castling.is_kingside_allowed(Color::White) && parsed.castling.is_queenside_allowed(Color::Black));
+        assert_eq!(parsed.en_passant, None);
+        assert_eq!(parsed.halfmove_clock, 0);
+        assert_eq!(parsed.fullmove_number, 1);
+    }
+
+    #[test]
+    fn format() {
+        let parsed = fen::parse(fen::STARTING_POSITION).unwrap();
+
+        assert_eq!(fen::format(&parsed), fen::STARTING_POSITION);
+    }
+
+
+    #[test]
+    fn parse_err() {
+        assert_eq!(fen::parse("8/8/8/8/8/8/8/8 w - -").err(), Some(FenError::FieldCount(4)));
+        assert_eq!(fen::parse("8/8/8/8/8/8/8/9 w - - 0 1").err(), Some(FenError::PiecePlacement("8/8/8/8/8/8/8/9".to_string())));
+        assert_eq!(fen::parse("8/8/8/8/8/8/8/7x w - - 0 1").err(), Some(FenError::PiecePlacement("8/8/8/8/8/8/8/7x".to_string())));
+        assert_eq!(fen::parse("8/8/8/8/8/8/8 w - - 0 1").err(), Some(FenError::PiecePlacement("8/8/8/8/8/8/8".to_string())));
+        assert_eq!(fen::parse("8/8/8/8/8/8/8/8 x - - 0 1").err(), Some(FenError::ActiveColor("x".to_string())));
+        assert_eq!(fen::parse("8/8/8/8/8/8/8/8 w KK - 0 1").err(), Some(FenError::Castling("KK".to_string())));
+        assert_eq!(fen::parse("8/8/8/8/8/8/8/8 w - e4 0 1").err(), Some(FenError::EnPassant("e4".to_string())));
+        assert_eq!(fen::parse("8/8/8/8/8/8/8/8 w - - x 1").err(), Some(FenError::HalfmoveClock("x".to_string())));
+        assert_eq!(fen::parse("8/8/8/8/8/8/8/8 w - - 0 0").err(), Some(FenError::FullmoveNumber("0".to_string())));
+    }
+}
@@ -1,8 +1,26 @@
-mod direction;
-mod piece;
+mod bitboard;
 mod role;
-mod square;
+mod see;
+mod zobrist;
 
 pub mod color;
 pub mod comp;
-pub mod board;
\ No newline at end of file
+pub mod board;
+pub mod error;
+pub mod eval;
+pub mod fen;
+pub mod mate;
+pub mod pgn;
+pub mod san;
+pub mod search;
+pub mod status;
+pub mod validate;
+
+#[cfg(feature = "capi")]
+pub mod capi;
+#[cfg(feature = "python")]
+mod python;
+#[cfg(feature = "serde")]
+mod serialize;
+#[cfg(feature = "wasm")]
+pub mod wasm;
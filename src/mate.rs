@@ -0,0 +1,242 @@
+use crate::board::Board;
+use crate::board::MoveList;
+use crate::comp::Move;
+
+/*
+ * Public Declarations:
+ * - struct MateSolution
+ * - struct MateReply
+ * - fn solve
+ */
+
+#[derive(Clone, Debug)]
+pub struct MateSolution {
+    key: Move,
+    // OTHER MOVES THAT FORCE MATE JUST AS FAST, COOKS ON THE FIRST MOVE AND DUALS LATER ON
+    alternatives: Vec<Move>,
+    // MOVES THAT STILL MATE WITHIN THE LIMIT, ONLY LATER THAN THE KEY
+    slower_alternatives: Vec<Move>,
+    replies: Vec<MateReply>,
+}
+
+impl MateSolution {
+    pub fn get_key(&self) -> Move {
+        self.key
+    }
+
+    pub fn get_alternatives(&self) -> &[Move] {
+        &self.alternatives
+    }
+
+    pub fn get_slower_alternatives(&self) -> &[Move] {
+        &self.slower_alternatives
+    }
+
+    // EMPTY WHEN THE KEY MATES AT ONCE
+    pub fn get_replies(&self) -> &[MateReply] {
+        &self.replies
+    }
+
+    pub fn has_cooks(&self) -> bool {
+        !self.alternatives.is_empty() || !self.slower_alternatives.is_empty()
+    }
+
+    pub fn has_duals(&self) -> bool {
+        self.replies.iter().any(|reply| reply.continuation.has_cooks() || reply.continuation.has_duals())
+    }
+}
+
+// ---
+
+#[derive(Clone, Debug)]
+pub struct MateReply {
+    defence: Move,
+    continuation: MateSolution,
+}
+
+impl MateReply {
+    pub fn get_defence(&self) -> Move {
+        self.defence
+    }
+
+    pub fn get_continuation(&self) -> &MateSolution {
+        &self.continuation
+    }
+}
+
+// ---
+
+// CHECKS FIRST, THEY NARROW THE DEFENCE THE MOST
+fn ordered_moves(board: &mut Board) -> MoveList {
+    let (mut checks, quiet): (MoveList, MoveList) = board.legal_moves().into_iter().partition(|&chess_move| {
+        let undo = board.make_move_unchecked(chess_move);
+        let check = board.is_check();
+        board.unmake_move(chess_move, undo);
+
+        check
+    });
+
+    checks.extend(quiet);
+    checks
+}
+
+fn forces_mate(board: &mut Board, chess_move: Move, moves: u32) -> bool {
+    let undo = board.make_move_unchecked(chess_move);
+
+    let mates = if !board.has_legal_moves() {
+        board.is_check()
+    } else if moves > 1 {
+        board.legal_moves().into_iter().all(|defence| {
+            let undo = board.make_move_unchecked(defence);
+            let mates = has_mate(board, moves - 1);
+            board.unmake_move(defence, undo);
+
+            mates
+        })
+    } else {
+        false
+    };
+
+    board.unmake_move(chess_move, undo);
+
+    mates
+}
+
+fn has_mate(board: &mut Board, moves: u32) -> bool {
+    ordered_moves(board).into_iter().any(|chess_move| forces_mate(board, chess_move, moves))
+}
+
+// THE KEY IS THE FASTEST MATE, THE TREE BELOW IT NEVER TAKES LONGER
+fn build(board: &mut Board, moves: u32) -> Option<MateSolution> {
+    let candidates = ordered_moves(board);
+
+    let fastest = (1..=moves).find(|&depth| candidates.iter().any(|&chess_move| forces_mate(board, chess_move, depth)))?;
+
+    let mut mating = MoveList::new();
+    let mut slower_alternatives = MoveList::new();
+
+    for chess_move in candidates {
+        if forces_mate(board, chess_move, fastest) {
+            mating.push(chess_move);
+        } else if forces_mate(board, chess_move, moves) {
+            slower_alternatives.push(chess_move);
+        }
+    }
+
+    let mut mating = mating.into_iter();
+    let key = mating.next()?;
+
+    let undo = board.make_move_unchecked(key);
+
+    let replies = board.legal_moves().into_iter().map(|defence| {
+        let undo = board.make_move_unchecked(defence);
+        let continuation = build(board, fastest - 1).expect("Every defence was checked to lose.");
+        board.unmake_move(defence, undo);
+
+        MateReply { defence, continuation }
+    }).collect();
+
+    board.unmake_move(key, undo);
+
+    Some(MateSolution {
+        key,
+        alternatives: mating.collect(),
+        slower_alternatives,
+        replies,
+    })
+}
+
+// MATE IN AT MOST THE GIVEN NUMBER OF MOVES FOR THE SIDE TO MOVE, NONE IF THE DEFENCE HOLDS
+pub fn solve(board: &Board, moves: u32) -> Option<MateSolution> {
+    if moves == 0 {
+        return None;
+    }
+
+    build(&mut board.clone(), moves)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::board::Board;
+    use crate::comp::ChessRole;
+    use crate::comp::ChessSquare;
+    use crate::comp::Move;
+    use crate::mate;
+
+    #[test]
+    fn solve_mate_in_one() {
+        let board = Board::from_fen("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1").unwrap();
+        let solution = mate::solve(&board, 1).unwrap();
+
+        assert_eq!(solution.get_key(), Move::new(ChessSquare::new(0, 0), ChessSquare::new(0, 7), None));
+        assert!(solution.get_replies().is_empty());
+        assert!(!solution.has_cooks() && !solution.has_duals());
+
+        assert!(mate::solve(&Board::new(), 1).is_none());
+        assert!(mate::solve(&board, 0).is_none());
+
+        // STALEMATE IS NO MATE
+        let board = Board::from_fen("k7/8/1Q6/8/8/8/8/K7 w - - 0 1").unwrap();
+
+        assert!(mate::solve(&board, 1).is_none());
+    }
+
+    #[test]
+    fn solve_cooks() {
+        // BOTH ROOKS MATE ON THE BACK RANK
+        let board = Board::from_fen("6k1/5ppp/8/8/8/8/8/R3R1K1 w - - 0 1").unwrap();
+        let solution = mate::solve(&board, 1).unwrap();
+
+        assert!(solution.has_cooks());
+        assert_eq!(solution.get_alternatives().len(), 1);
+    }
+
+    #[test]
+    fn solve_mate_in_two() {
+        let board = Board::from_fen("k7/8/2K5/8/8/8/8/7R w - - 0 1").unwrap();
+
+        assert!(mate::solve(&board, 1).is_none());
+
+        let solution = mate::solve(&board, 2).unwrap();
+        let square = |file, rank| ChessSquare::new(file, rank);
+
+        // Kb6 AND Kc7 BOTH WORK, THE ROOK MATES ON THE BACK RANK OR THE A FILE
+        assert_eq!(solution.get_key(), Move::new(square(2, 5), square(1, 5), None));
+        assert_eq!(solution.get_alternatives(), [Move::new(square(2, 5), square(2, 6), None)]);
+        assert_eq!(solution.get_replies().len(), 1);
+
+        let reply = &solution.get_replies()[0];
+
+        assert_eq!(reply.get_defence(), Move::new(square(0, 7), square(1, 7), None));
+        assert_eq!(reply.get_continuation().get_key(), Move::new(square(7, 0), square(7, 7), None));
+        assert!(reply.get_continuation().get_replies().is_empty());
+        assert!(solution.has_cooks() && !solution.has_duals());
+    }
+
+    #[test]
+    fn solve_slower_alternatives() {
+        let board = Board::from_fen("k7/8/2K5/8/8/8/8/7R w - - 0 1").unwrap();
+        let solution = mate::solve(&board, 3).unwrap();
+        let square = |file, rank| ChessSquare::new(file, rank);
+
+        // THE MATE IN TWO STAYS THE KEY, ROOK MOVES THAT MATE IN THREE ARE LISTED APART
+        assert_eq!(solution.get_key(), Move::new(square(2, 5), square(1, 5), None));
+        assert_eq!(solution.get_alternatives(), [Move::new(square(2, 5), square(2, 6), None)]);
+        assert!(solution.get_slower_alternatives().contains(&Move::new(square(7, 0), square(0, 0), None)));
+        assert!(solution.get_replies().iter().all(|reply| reply.get_continuation().get_replies().is_empty()));
+    }
+
+    #[test]
+    fn solve_duals() {
+        let board = Board::from_fen("k7/3P4/2K5/8/8/8/8/7R w - - 0 1").unwrap();
+        let solution = mate::solve(&board, 2).unwrap();
+
+        // AFTER Ra1+ Kb8 BOTH d8=Q AND d8=R MATE
+        let continuation = solution.get_replies()[0].get_continuation();
+
+        assert_eq!(solution.get_key(), Move::new(ChessSquare::new(7, 0), ChessSquare::new(0, 0), None));
+        assert_eq!(continuation.get_key(), Move::new(ChessSquare::new(3, 6), ChessSquare::new(3, 7), Some(ChessRole::Queen)));
+        assert_eq!(continuation.get_alternatives(), [Move::new(ChessSquare::new(3, 6), ChessSquare::new(3, 7), Some(ChessRole::Rook))]);
+        assert!(solution.has_duals());
+    }
+}
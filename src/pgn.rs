@@ -0,0 +1,790 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::board::Board;
+use crate::board::MoveOutcome;
+use crate::color::Color;
+use crate::comp::ChessRole;
+use crate::comp::ChessSquare;
+use crate::error::ChessError;
+use crate::fen::FenError;
+use crate::san::SanError;
+use crate::status::GameStatus;
+
+/*
+ * Public Declarations:
+ * - struct Game
+ * - struct GameMove
+ * - enum PgnError
+ */
+
+const SEVEN_TAG_ROSTER: [&str; 7] = ["Event", "Site", "Date", "Round", "White", "Black", "Result"];
+
+const MAX_LINE_LENGTH: usize = 79;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum PgnError {
+    NoGame,
+    InvalidTag(String),
+    UnterminatedComment,
+    UnbalancedVariation,
+    UnexpectedToken(String),
+    InvalidFen(FenError),
+    InvalidMove(SanError),
+}
+
+impl fmt::Display for PgnError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PgnError::NoGame => write!(f, "No game found in PGN."),
+            PgnError::InvalidTag(tag) => write!(f, "Invalid tag pair \"{}\".", tag),
+            PgnError::UnterminatedComment => write!(f, "Comment is never closed."),
+            PgnError::UnbalancedVariation => write!(f, "Variation parentheses are unbalanced."),
+            PgnError::UnexpectedToken(token) => write!(f, "Unexpected token \"{}\".", token),
+            PgnError::InvalidFen(err) => write!(f, "Invalid FEN tag: {}", err),
+            PgnError::InvalidMove(err) => write!(f, "Invalid move: {}", err),
+        }
+    }
+}
+
+impl Error for PgnError {}
+
+impl From<FenError> for PgnError {
+    fn from(err: FenError) -> Self {
+        PgnError::InvalidFen(err)
+    }
+}
+
+impl From<SanError> for PgnError {
+    fn from(err: SanError) -> Self {
+        PgnError::InvalidMove(err)
+    }
+}
+
+// ---
+
+#[derive(Clone, Debug)]
+pub struct GameMove {
+    san: String,
+
+    from: ChessSquare,
+    to: ChessSquare,
+    promotion: Option<ChessRole>,
+
+    nags: Vec<u8>,
+    pre_comment: Option<String>,
+    comment: Option<String>,
+    variations: Vec<Vec<GameMove>>,
+}
+
+impl GameMove {
+    fn new(san: String, from: ChessSquare, to: ChessSquare, promotion: Option<ChessRole>) -> GameMove {
+        GameMove {
+            san,
+            from,
+            to,
+            promotion,
+            nags: Vec::new(),
+            pre_comment: None,
+            comment: None,
+            variations: Vec::new(),
+        }
+    }
+
+    pub fn get_san(&self) -> &str {
+        &self.san
+    }
+
+    pub fn get_from(&self) -> ChessSquare {
+        self.from
+    }
+
+    pub fn get_to(&self) -> ChessSquare {
+        self.to
+    }
+
+    pub fn get_promotion(&self) -> Option<ChessRole> {
+        self.promotion
+    }
+
+    pub fn get_nags(&self) -> &[u8] {
+        &self.nags
+    }
+
+    pub fn get_pre_comment(&self) -> Option<&str> {
+        self.pre_comment.as_deref()
+    }
+
+    pub fn get_comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+
+    pub fn get_variations(&self) -> &[Vec<GameMove>] {
+        &self.variations
+    }
+}
+
+// ---
+
+#[derive(Clone)]
+pub struct Game {
+    tags: Vec<(String, String)>,
+
+    initial_board: Board,
+    board: Board,
+
+    moves: Vec<GameMove>,
+}
+
+impl Game {
+    pub fn new() -> Game {
+        Game::from_board(Board::new())
+    }
+
+    pub fn from_fen(fen: &str) -> Result<Game, FenError> {
+        let mut game = Game::from_board(Board::from_fen(fen)?);
+
+        game.set_tag("SetUp", "1");
+        game.set_tag("FEN", fen);
+
+        Ok(game)
+    }
+
+    fn from_board(board: Board) -> Game {
+        let mut tags: Vec<(String, String)> = SEVEN_TAG_ROSTER.iter().map(|&name| (name.to_string(), "?".to_string())).collect();
+        tags[2].1 = "????.??.??".to_string();
+        tags[6].1 = "*".to_string();
+
+        Game {
+            tags,
+            initial_board: board.clone(),
+            board,
+            moves: Vec::new(),
+        }
+    }
+
+    pub fn from_pgn(pgn: &str) -> Result<Game, PgnError> {
+        let mut games = Game::all_from_pgn(pgn)?;
+
+        if games.is_empty() {
+            return Err(PgnError::NoGame);
+        }
+
+        Ok(games.remove(0))
+    }
+
+    pub fn all_from_pgn(pgn: &str) -> Result<Vec<Game>, PgnError> {
+        let tokens = tokenize(pgn)?;
+
+        let mut games: Vec<Game> = Vec::new();
+        let mut pos = 0;
+
+        while pos < tokens.len() {
+            games.push(parse_game(&tokens, &mut pos)?);
+        }
+
+        Ok(games)
+    }
+
+    pub fn get_tag(&self, name: &str) -> Option<&str> {
+        self.tags.iter().find(|(tag, _)| tag == name).map(|(_, value)| value.as_str())
+    }
+
+    pub fn set_tag(&mut self, name: &str, value: &str) {
+        match self.tags.iter_mut().find(|(tag, _)| tag == name) {
+            Some(tag) => tag.1 = value.to_string(),
+            None => self.tags.push((name.to_string(), value.to_string())),
+        }
+    }
+
+    pub fn get_result(&self) -> &str {
+        self.get_tag("Result").unwrap_or("*")
+    }
+
+    pub fn set_result(&mut self, result: &str) {
+        self.set_tag("Result", result);
+    }
+
+    pub fn get_moves(&self) -> &[GameMove] {
+        &self.moves
+    }
+
+    pub fn get_board(&self) -> &Board {
+        &self.board
+    }
+
+    pub fn play(&mut self, from: ChessSquare, to: ChessSquare, promotion: Option<ChessRole>) -> Result<MoveOutcome, ChessError> {
+        let outcome = self.board.move_piece_with_promotion(from, to, promotion)?;
+
+        // A CHECK MATE OR AN AUTOMATIC DRAW ENDS THE GAME
+        match outcome.get_status() {
+            GameStatus::Checkmate(Color::White) => self.set_result("1-0"),
+            GameStatus::Checkmate(Color::Black) => self.set_result("0-1"),
+            status if status.is_over() => self.set_result("1/2-1/2"),
+            _ => (),
+        }
+
+        self.moves.push(GameMove::new(outcome.get_san().to_string(), from, to, outcome.get_promotion()));
+
+        Ok(outcome)
+    }
+
+    pub fn play_san(&mut self, san: &str) -> Result<MoveOutcome, ChessError> {
+        let (from, to, promotion) = self.board.parse_san(san)?;
+
+        self.play(from, to, promotion)
+    }
+
+    pub fn to_pgn(&self) -> String {
+        let mut pgn = String::new();
+
+        // SEVEN TAG ROSTER FIRST, THEN THE REST IN ORDER OF APPEARANCE
+        for &name in SEVEN_TAG_ROSTER.iter() {
+            let value = self.get_tag(name).unwrap_or("?");
+            pgn.push_str(&format!("[{} \"{}\"]\n", name, escape_tag_value(value)));
+        }
+
+        for (name, value) in self.tags.iter() {
+            if !SEVEN_TAG_ROSTER.contains(&name.as_str()) {
+                pgn.push_str(&format!("[{} \"{}\"]\n", name, escape_tag_value(value)));
+            }
+        }
+
+        pgn.push('\n');
+
+        let mut tokens = write_line(&self.moves, self.initial_board.get_fullmove_number(), self.initial_board.get_active_color());
+        tokens.push(self.get_result().to_string());
+
+        let mut line = String::new();
+
+        for token in tokens {
+            if !line.is_empty() && line.len() + 1 + token.len() > MAX_LINE_LENGTH {
+                pgn.push_str(&line);
+                pgn.push('\n');
+                line.clear();
+            }
+
+            if !line.is_empty() {
+                line.push(' ');
+            }
+
+            line.push_str(&token);
+        }
+
+        pgn.push_str(&line);
+        pgn.push('\n');
+
+        pgn
+    }
+}
+
+impl Default for Game {
+    fn default() -> Self {
+        Game::new()
+    }
+}
+
+// ---
+
+#[derive(Clone, PartialEq, Debug)]
+enum Token {
+    Tag(String, String),
+    Comment(String),
+    VariationStart,
+    VariationEnd,
+    Nag(u8),
+    San(String),
+    Result(String),
+}
+
+fn escape_tag_value(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn suffix_to_nag(suffix: &str) -> Option<u8> {
+    match suffix {
+        "!" => Some(1),
+        "?" => Some(2),
+        "!!" => Some(3),
+        "??" => Some(4),
+        "!?" => Some(5),
+        "?!" => Some(6),
+        _ => None,
+    }
+}
+
+fn read_tag(chars: &mut std::iter::Peekable<std::str::Chars>) -> Result<Token, PgnError> {
+    let mut raw = String::new();
+    let mut name = String::new();
+    let mut value = String::new();
+    let mut in_value = false;
+    let mut value_done = false;
+
+    loop {
+        let c = match chars.next() {
+            Some(c) => c,
+            None => return Err(PgnError::InvalidTag(raw)),
+        };
+
+        if in_value {
+            match c {
+                '\\' => {
+                    if let Some(escaped) = chars.next() {
+                        raw.push(escaped);
+                        value.push(escaped);
+                    }
+                },
+                '"' => {
+                    in_value = false;
+                    value_done = true;
+                },
+                _ => value.push(c),
+            }
+
+            raw.push(c);
+            continue;
+        }
+
+        match c {
+            ']' => break,
+            '"' if !value_done && !name.is_empty() => in_value = true,
+            _ if c.is_whitespace() => {},
+            _ if !value_done && (c.is_alphanumeric() || c == '_') => name.push(c),
+            _ => return Err(PgnError::InvalidTag(raw)),
+        }
+
+        raw.push(c);
+    }
+
+    if name.is_empty() || !value_done {
+        return Err(PgnError::InvalidTag(raw));
+    }
+
+    Ok(Token::Tag(name, value))
+}
+
+fn push_symbol(symbol: &str, tokens: &mut Vec<Token>) -> Result<(), PgnError> {
+    match symbol {
+        "1-0" | "0-1" | "1/2-1/2" | "*" => {
+            tokens.push(Token::Result(symbol.to_string()));
+            return Ok(());
+        },
+        _ => {},
+    }
+
+    let mut rest = symbol;
+
+    // MOVE NUMBER INDICATIONS LIKE "12." OR "12..." MAY BE GLUED TO THE MOVE
+    if !symbol.starts_with("0-0") {
+        let digits = symbol.chars().take_while(|c| c.is_ascii_digit()).count();
+        let dots = symbol[digits..].chars().take_while(|&c| c == '.').count();
+
+        if digits > 0 && dots == 0 && digits < symbol.len() {
+            return Err(PgnError::UnexpectedToken(symbol.to_string()));
+        }
+
+        rest = &symbol[digits + dots..];
+    }
+
+    // THE EN PASSENT SUFFIX CARRIES NO INFORMATION
+    if rest.is_empty() || rest == "e.p." {
+        return Ok(());
+    }
+
+    let annotation_length = rest.chars().rev().take_while(|&c| c == '!' || c == '?').count();
+    let annotation = &rest[rest.len() - annotation_length..];
+    rest = &rest[..rest.len() - annotation_length];
+
+    if !rest.is_empty() {
+        tokens.push(Token::San(rest.to_string()));
+    }
+
+    if !annotation.is_empty() {
+        match suffix_to_nag(annotation) {
+            Some(nag) => tokens.push(Token::Nag(nag)),
+            None => return Err(PgnError::UnexpectedToken(symbol.to_string())),
+        }
+    }
+
+    Ok(())
+}
+
+fn tokenize(pgn: &str) -> Result<Vec<Token>, PgnError> {
+    let mut tokens: Vec<Token> = Vec::new();
+
+    let mut chars = pgn.chars().peekable();
+    let mut line_start = true;
+
+    while let Some(c) = chars.next() {
+        let at_line_start = line_start;
+        line_start = c == '\n';
+
+        match c {
+            _ if c.is_whitespace() => {},
+            '%' if at_line_start => {
+                // ESCAPE MECHANISM, THE REST OF THE LINE IS IGNORED
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        line_start = true;
+                        break;
+                    }
+                }
+            },
+            '[' => tokens.push(read_tag(&mut chars)?),
+            '{' => {
+                let mut comment = String::new();
+                let mut closed = false;
+
+                for c in chars.by_ref() {
+                    if c == '}' {
+                        closed = true;
+                        break;
+                    }
+
+                    comment.push(c);
+                }
+
+                if !closed {
+                    return Err(PgnError::UnterminatedComment);
+                }
+
+                tokens.push(Token::Comment(comment.split_whitespace().collect::<Vec<&str>>().join(" ")));
+            },
+            ';' => {
+                let mut comment = String::new();
+
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        line_start = true;
+                        break;
+                    }
+
+                    comment.push(c);
+                }
+
+                tokens.push(Token::Comment(comment.trim().to_string()));
+            },
+            '(' => tokens.push(Token::VariationStart),
+            ')' => tokens.push(Token::VariationEnd),
+            '$' => {
+                let mut digits = String::new();
+
+                while let Some(&c) = chars.peek() {
+                    if !c.is_ascii_digit() {
+                        break;
+                    }
+
+                    digits.push(c);
+                    chars.next();
+                }
+
+                match digits.parse::<u8>() {
+                    Ok(nag) => tokens.push(Token::Nag(nag)),
+                    Err(_) => return Err(PgnError::UnexpectedToken(format!("${}", digits))),
+                }
+            },
+            _ => {
+                let mut symbol = c.to_string();
+
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() || "[]{}();$".contains(c) {
+                        break;
+                    }
+
+                    symbol.push(c);
+                    chars.next();
+                }
+
+                push_symbol(&symbol, &mut tokens)?;
+            },
+        }
+    }
+
+    Ok(tokens)
+}
+
+// ---
+
+fn append_comment(target: &mut Option<String>, comment: &str) {
+    match target {
+        Some(existing) => {
+            existing.push(' ');
+            existing.push_str(comment);
+        },
+        None => *target = Some(comment.to_string()),
+    }
+}
+
+fn parse_line(tokens: &[Token], pos: &mut usize, mut board: Board) -> Result<(Vec<GameMove>, Board), PgnError> {
+    let mut moves: Vec<GameMove> = Vec::new();
+
+    let mut board_before_last: Option<Board> = None;
+    let mut pending_comment: Option<String> = None;
+
+    while let Some(token) = tokens.get(*pos) {
+        match token {
+            Token::Tag(_, _) | Token::Result(_) | Token::VariationEnd => break,
+            Token::Comment(comment) => {
+                match moves.last_mut() {
+                    Some(last) => append_comment(&mut last.comment, comment),
+                    None => append_comment(&mut pending_comment, comment),
+                }
+            },
+            Token::Nag(nag) => {
+                match moves.last_mut() {
+                    Some(last) => last.nags.push(*nag),
+                    None => return Err(PgnError::UnexpectedToken(format!("${}", nag))),
+                }
+            },
+            Token::VariationStart => {
+                // A VARIATION REPLACES THE LAST MOVE PLAYED
+                let base = match &board_before_last {
+                    Some(base) => base.clone(),
+                    None => return Err(PgnError::UnexpectedToken("(".to_string())),
+                };
+
+                *pos += 1;
+                let (variation, _) = parse_line(tokens, pos, base)?;
+
+                if tokens.get(*pos) != Some(&Token::VariationEnd) {
+                    return Err(PgnError::UnbalancedVariation);
+                }
+
+                moves.last_mut().unwrap().variations.push(variation);
+            },
+            Token::San(san) => {
+                let (from, to, promotion) = board.parse_san(san)?;
+                let canonical_san = board.to_san(from, to, promotion)?;
+
+                board_before_last = Some(board.clone());
+                board.move_piece_with_promotion(from, to, promotion).map_err(|_| SanError::IllegalMove(san.to_string()))?;
+
+                let mut game_move = GameMove::new(canonical_san, from, to, promotion);
+                game_move.pre_comment = pending_comment.take();
+
+                moves.push(game_move);
+            },
+        }
+
+        *pos += 1;
+    }
+
+    Ok((moves, board))
+}
+
+fn parse_game(tokens: &[Token], pos: &mut usize) -> Result<Game, PgnError> {
+    let mut tags: Vec<(String, String)> = Vec::new();
+
+    while let Some(Token::Tag(name, value)) = tokens.get(*pos) {
+        tags.push((name.to_string(), value.to_string()));
+        *pos += 1;
+    }
+
+    let mut game = match tags.iter().find(|(name, _)| name == "FEN") {
+        Some((_, fen)) => Game::from_fen(fen)?,
+        None => Game::new(),
+    };
+
+    for (name, value) in tags.iter() {
+        game.set_tag(name, value);
+    }
+
+    let (moves, board) = parse_line(tokens, pos, game.board.clone())?;
+
+    game.moves = moves;
+    game.board = board;
+
+    match tokens.get(*pos) {
+        Some(Token::Result(result)) => {
+            game.set_result(result);
+            *pos += 1;
+        },
+        Some(Token::VariationEnd) => return Err(PgnError::UnbalancedVariation),
+        _ => {},
+    }
+
+    Ok(game)
+}
+
+fn write_line(moves: &[GameMove], mut number: u32, mut color: Color) -> Vec<String> {
+    let mut tokens: Vec<String> = Vec::new();
+
+    let mut needs_number = true;
+
+    for game_move in moves {
+        if let Some(comment) = &game_move.pre_comment {
+            tokens.push(format!("{{{}}}", comment));
+            needs_number = true;
+        }
+
+        match color {
+            Color::White => tokens.push(format!("{}.", number)),
+            Color::Black if needs_number => tokens.push(format!("{}...", number)),
+            _ => {},
+        }
+
+        tokens.push(game_move.san.clone());
+        needs_number = false;
+
+        for nag in game_move.nags.iter() {
+            tokens.push(format!("${}", nag));
+        }
+
+        if let Some(comment) = &game_move.comment {
+            tokens.push(format!("{{{}}}", comment));
+            needs_number = true;
+        }
+
+        for variation in game_move.variations.iter() {
+            let mut variation_tokens = write_line(variation, number, color);
+
+            if let Some(first) = variation_tokens.first_mut() {
+                first.insert(0, '(');
+            }
+            if let Some(last) = variation_tokens.last_mut() {
+                last.push(')');
+            }
+
+            tokens.append(&mut variation_tokens);
+            needs_number = true;
+        }
+
+        if color == Color::Black {
+            number += 1;
+        }
+
+        color = match color {
+            Color::White => Color::Black,
+            Color::Black => Color::White,
+        };
+    }
+
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pgn::Game;
+    use crate::pgn::PgnError;
+    use crate::san::SanError;
+    use crate::comp::ChessRole;
+    use crate::comp::ChessSquare;
+    use crate::status::GameStatus;
+
+    const OPERA_GAME: &str = r#"[Event "Paris"]
+[Site "Paris FRA"]
+[Date "1858.??.??"]
+[Round "?"]
+[White "Paul Morphy"]
+[Black "Duke Karl / Count Isouard"]
+[Result "1-0"]
+
+1. e4 e5 2. Nf3 d6 3. d4 Bg4 {This is a weak move already.} 4. dxe5 Bxf3 5. Qxf3 dxe5
+6. Bc4 Nf6 7. Qb3 Qe7 8. Nc3 c6 9. Bg5 b5 10. Nxb5 cxb5 11. Bxb5+ Nbd7 12. O-O-O
+Rd8 13. Rxd7 Rxd7 14. Rd1 Qe6 15. Bxd7+ Nxd7 16. Qb8+ Nxb8 17. Rd8# 1-0
+"#;
+
+    #[test]
+    fn from_pgn() {
+        let game = Game::from_pgn(OPERA_GAME).unwrap();
+
+        assert_eq!(game.get_tag("White"), Some("Paul Morphy"));
+        assert_eq!(game.get_result(), "1-0");
+        assert_eq!(game.get_moves().len(), 33);
+        assert_eq!(game.get_moves()[5].get_comment(), Some("This is a weak move already."));
+        assert_eq!(game.get_moves()[22].get_san(), "O-O-O");
+        assert_eq!(game.get_moves()[32].get_san(), "Rd8#");
+        assert_eq!(game.get_board().to_fen(), "1n1Rkb1r/p4ppp/4q3/4p1B1/4P3/8/PPP2PPP/2K5 b k - 1 17");
+    }
+
+    #[test]
+    fn to_pgn() {
+        let game = Game::from_pgn(OPERA_GAME).unwrap();
+        let pgn = game.to_pgn();
+
+        assert!(pgn.starts_with("[Event \"Paris\"]\n[Site \"Paris FRA\"]\n[Date \"1858.??.??\"]\n[Round \"?\"]\n"));
+        assert!(pgn.contains("3. d4 Bg4 {This is a weak move already.} 4. dxe5 Bxf3"));
+        assert!(pgn.ends_with("Rd8# 1-0\n"));
+        assert!(pgn.lines().all(|line| line.len() < 80));
+
+        let reparsed = Game::from_pgn(&pgn).unwrap();
+
+        assert_eq!(reparsed.to_pgn(), pgn);
+    }
+
+    #[test]
+    fn variations() {
+        let pgn = "1. e4 $1 e5 (1... c5!? {Sicilian} 2. Nf3 (2. c3) d6) (1... e6) 2. Nf3 *";
+        let game = Game::from_pgn(pgn).unwrap();
+
+        let moves = game.get_moves();
+
+        assert_eq!(moves.len(), 3);
+        assert_eq!(moves[0].get_nags(), &[1]);
+        assert_eq!(moves[1].get_variations().len(), 2);
+        assert_eq!(moves[1].get_variations()[0][0].get_nags(), &[5]);
+        assert_eq!(moves[1].get_variations()[0][0].get_comment(), Some("Sicilian"));
+        assert_eq!(moves[1].get_variations()[0][1].get_variations()[0][0].get_san(), "c3");
+        assert_eq!(game.get_result(), "*");
+
+        assert!(game.to_pgn().ends_with("1. e4 $1 e5 (1... c5 $5 {Sicilian} 2. Nf3 (2. c3) 2... d6) (1... e6) 2. Nf3 *\n"));
+    }
+
+    #[test]
+    fn from_pgn_fen() {
+        let pgn = "[FEN \"4k3/P7/8/8/8/8/8/4K3 w - - 0 1\"]\n[SetUp \"1\"]\n\n1. a8=N Kd7 *";
+        let game = Game::from_pgn(pgn).unwrap();
+
+        assert_eq!(game.get_moves()[0].get_promotion(), Some(ChessRole::Knight));
+        assert_eq!(game.get_board().to_fen(), "N7/3k4/8/8/8/8/8/4K3 w - - 1 2");
+
+        let game = Game::from_pgn("[FEN \"4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 2\"]\n\n2. exd6 e.p. *").unwrap();
+
+        assert_eq!(game.get_moves()[0].get_san(), "exd6");
+        assert_eq!(game.get_board().to_fen(), "4k3/8/3P4/8/8/8/8/4K3 b - - 0 2");
+    }
+
+    #[test]
+    fn all_from_pgn() {
+        let pgn = "[Event \"A\"]\n\n1. e4 1-0\n\n[Event \"B\"]\n\n1. d4 d5 0-1\n\n% escaped line\n[Event \"C\"]\n\n; line comment\n1. c4 1/2-1/2\n";
+        let games = Game::all_from_pgn(pgn).unwrap();
+
+        assert_eq!(games.len(), 3);
+        assert_eq!(games[1].get_tag("Event"), Some("B"));
+        assert_eq!(games[1].get_result(), "0-1");
+        assert_eq!(games[2].get_moves()[0].get_pre_comment(), Some("line comment"));
+    }
+
+    #[test]
+    fn from_pgn_err() {
+        assert_eq!(Game::from_pgn("").err(), Some(PgnError::NoGame));
+        assert_eq!(Game::from_pgn("1. e4 {open").err(), Some(PgnError::UnterminatedComment));
+        assert_eq!(Game::from_pgn("1. e4 (1. d4").err(), Some(PgnError::UnbalancedVariation));
+        assert_eq!(Game::from_pgn("1. e4 e5 2. Ke3").err(), Some(PgnError::InvalidMove(SanError::IllegalMove("Ke3".to_string()))));
+        assert_eq!(Game::from_pgn("1. Nd2").err(), Some(PgnError::InvalidMove(SanError::IllegalMove("Nd2".to_string()))));
+        assert_eq!(Game::from_pgn("1. e4é e5 *").err(), Some(PgnError::InvalidMove(SanError::InvalidSyntax("e4é".to_string()))));
+        assert_eq!(Game::from_pgn("1. e4 e5 2. Nf3 Nc6 3. Nc3 Nf6 4. Nd4 Nd5 5. Nb5").err(), Some(PgnError::InvalidMove(SanError::AmbiguousMove("Nb5".to_string()))));
+        assert_eq!(Game::from_pgn("[Event \"A]").err(), Some(PgnError::InvalidTag("Event \"A]".to_string())));
+    }
+
+    #[test]
+    fn play() {
+        let mut game = Game::new();
+        game.set_tag("White", "A \"quoted\" name");
+
+        for &(from, to) in [((5, 1), (5, 2)), ((4, 6), (4, 4)), ((6, 1), (6, 3)), ((3, 7), (7, 3))].iter() {
+            game.play(ChessSquare::new(from.0, from.1), ChessSquare::new(to.0, to.1), None).unwrap();
+        }
+
+        assert_eq!(game.get_result(), "0-1");
+        assert_eq!(game.to_pgn(), "[Event \"?\"]\n[Site \"?\"]\n[Date \"????.??.??\"]\n[Round \"?\"]\n[White \"A \\\"quoted\\\" name\"]\n[Black \"?\"]\n[Result \"0-1\"]\n\n1. f3 e5 2. g4 Qh4# 0-1\n");
+
+        assert!(game.play_san("Kf2").is_err());
+
+        // DEAD POSITIONS ARE DRAWN AT ONCE
+        let mut game = Game::from_fen("4k3/8/8/8/8/8/3p4/2B1K3 w - - 0 1").unwrap();
+
+        assert_eq!(game.play_san("Bxd2").unwrap().get_status(), GameStatus::InsufficientMaterial);
+        assert_eq!(game.get_result(), "1/2-1/2");
+    }
+}
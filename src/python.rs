@@ -0,0 +1,375 @@
+// THE PYMETHODS MACRO CONVERTS EVERY PYRESULT ERROR, EVEN WHEN IT ALREADY IS A PYERR
+#![allow(clippy::useless_conversion)]
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::board::Board;
+use crate::color::Color;
+use crate::comp::ChessPiece;
+use crate::comp::ChessRole;
+use crate::comp::ChessSquare;
+use crate::comp::Move;
+use crate::status::GameStatus;
+
+/*
+ * Python classes, only built with the python feature:
+ * - Color
+ * - ChessRole
+ * - ChessSquare
+ * - ChessPiece
+ * - Move
+ * - Board
+ */
+
+fn value_error<E: std::fmt::Display>(err: E) -> PyErr {
+    PyValueError::new_err(format!("{}", err))
+}
+
+#[pyclass(name = "Color", eq, eq_int, hash, frozen)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+enum PyColor {
+    White,
+    Black,
+}
+
+impl From<Color> for PyColor {
+    fn from(color: Color) -> Self {
+        match color {
+            Color::White => PyColor::White,
+            Color::Black => PyColor::Black,
+        }
+    }
+}
+
+impl From<PyColor> for Color {
+    fn from(color: PyColor) -> Self {
+        match color {
+            PyColor::White => Color::White,
+            PyColor::Black => Color::Black,
+        }
+    }
+}
+
+// ---
+
+#[pyclass(name = "ChessRole", eq, eq_int, hash, frozen)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+enum PyRole {
+    King,
+    Queen,
+    Bichop,
+    Knight,
+    Rook,
+    Pawn,
+}
+
+impl From<ChessRole> for PyRole {
+    fn from(role: ChessRole) -> Self {
+        match role {
+            ChessRole::King => PyRole::King,
+            ChessRole::Queen => PyRole::Queen,
+            ChessRole::Bichop => PyRole::Bichop,
+            ChessRole::Knight => PyRole::Knight,
+            ChessRole::Rook => PyRole::Rook,
+            ChessRole::Pawn => PyRole::Pawn,
+        }
+    }
+}
+
+impl From<PyRole> for ChessRole {
+    fn from(role: PyRole) -> Self {
+        match role {
+            PyRole::King => ChessRole::King,
+            PyRole::Queen => ChessRole::Queen,
+            PyRole::Bichop => ChessRole::Bichop,
+            PyRole::Knight => ChessRole::Knight,
+            PyRole::Rook => ChessRole::Rook,
+            PyRole::Pawn => ChessRole::Pawn,
+        }
+    }
+}
+
+// ---
+
+#[pyclass(name = "ChessSquare", eq, hash, frozen)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+struct PySquare {
+    file: u8,
+    rank: u8,
+}
+
+impl From<ChessSquare> for PySquare {
+    fn from(square: ChessSquare) -> Self {
+        PySquare { file: square.get_file(), rank: square.get_rank() }
+    }
+}
+
+impl From<PySquare> for ChessSquare {
+    fn from(square: PySquare) -> Self {
+        ChessSquare::new(square.file, square.rank)
+    }
+}
+
+#[pymethods]
+impl PySquare {
+    #[new]
+    fn new(file: u8, rank: u8) -> PyResult<PySquare> {
+        ChessSquare::try_new(file, rank).map(PySquare::from).map_err(value_error)
+    }
+
+    #[staticmethod]
+    fn parse(name: &str) -> PyResult<PySquare> {
+        name.parse::<ChessSquare>().map(PySquare::from).map_err(value_error)
+    }
+
+    #[getter]
+    fn file(&self) -> u8 {
+        self.file
+    }
+
+    #[getter]
+    fn rank(&self) -> u8 {
+        self.rank
+    }
+
+    fn __str__(&self) -> String {
+        ChessSquare::from(*self).to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ChessSquare({}, {})", self.file, self.rank)
+    }
+}
+
+// ---
+
+#[pyclass(name = "ChessPiece", eq, hash, frozen)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+struct PyPiece {
+    color: PyColor,
+    role: PyRole,
+}
+
+impl From<ChessPiece> for PyPiece {
+    fn from(piece: ChessPiece) -> Self {
+        PyPiece { color: piece.get_color().into(), role: piece.get_role().into() }
+    }
+}
+
+#[pymethods]
+impl PyPiece {
+    #[new]
+    fn new(color: PyColor, role: PyRole) -> PyPiece {
+        PyPiece { color, role }
+    }
+
+    #[getter]
+    fn color(&self) -> PyColor {
+        self.color
+    }
+
+    #[getter]
+    fn role(&self) -> PyRole {
+        self.role
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ChessPiece(Color.{:?}, ChessRole.{})", Color::from(self.color), ChessRole::from(self.role))
+    }
+}
+
+// ---
+
+// EQUAL AND HASHED BY FROM, TO AND PROMOTION LIKE THE RUST MOVE
+#[pyclass(name = "Move", eq, hash, frozen)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+struct PyMove {
+    chess_move: Move,
+}
+
+#[pymethods]
+impl PyMove {
+    #[new]
+    #[pyo3(signature = (from_square, to_square, promotion=None))]
+    fn new(from_square: PySquare, to_square: PySquare, promotion: Option<PyRole>) -> PyResult<PyMove> {
+        Move::try_new(from_square.into(), to_square.into(), promotion.map(ChessRole::from))
+            .map(|chess_move| PyMove { chess_move })
+            .map_err(value_error)
+    }
+
+    #[getter(from_square)]
+    fn get_from(&self) -> PySquare {
+        self.chess_move.get_from().into()
+    }
+
+    #[getter(to_square)]
+    fn get_to(&self) -> PySquare {
+        self.chess_move.get_to().into()
+    }
+
+    #[getter]
+    fn promotion(&self) -> Option<PyRole> {
+        self.chess_move.get_promotion().map(PyRole::from)
+    }
+
+    // ONLY SET ON MOVES FROM Board.legal_moves()
+    #[getter]
+    fn piece(&self) -> Option<PyPiece> {
+        self.chess_move.get_piece().map(PyPiece::from)
+    }
+
+    #[getter]
+    fn captured(&self) -> Option<PyRole> {
+        self.chess_move.get_captured().map(PyRole::from)
+    }
+
+    fn is_capture(&self) -> bool {
+        self.chess_move.is_capture()
+    }
+
+    fn is_castling(&self) -> bool {
+        self.chess_move.is_castling()
+    }
+
+    fn is_en_passent(&self) -> bool {
+        self.chess_move.is_en_passent()
+    }
+
+    fn __str__(&self) -> String {
+        self.chess_move.to_uci()
+    }
+
+    fn __repr__(&self) -> String {
+        match self.chess_move.get_promotion() {
+            Some(role) => format!("Move({}, {}, ChessRole.{})", self.get_from().__repr__(), self.get_to().__repr__(), role),
+            None => format!("Move({}, {})", self.get_from().__repr__(), self.get_to().__repr__()),
+        }
+    }
+}
+
+// ---
+
+#[pyclass(name = "Board")]
+#[derive(Clone)]
+struct PyBoard {
+    board: Board,
+}
+
+#[pymethods]
+impl PyBoard {
+    #[new]
+    #[pyo3(signature = (fen=None))]
+    fn new(fen: Option<&str>) -> PyResult<PyBoard> {
+        let board = match fen {
+            Some(fen) => Board::from_fen(fen).map_err(value_error)?,
+            None => Board::new(),
+        };
+
+        Ok(PyBoard { board })
+    }
+
+    fn fen(&self) -> String {
+        self.board.to_fen()
+    }
+
+    fn copy(&self) -> PyBoard {
+        self.clone()
+    }
+
+    #[getter]
+    fn turn(&self) -> PyColor {
+        self.board.get_active_color().into()
+    }
+
+    #[getter]
+    fn halfmove_clock(&self) -> u32 {
+        self.board.get_halfmove_clock()
+    }
+
+    #[getter]
+    fn fullmove_number(&self) -> u32 {
+        self.board.get_fullmove_number()
+    }
+
+    fn piece_at(&self, square: PySquare) -> Option<PyPiece> {
+        self.board.get_active_pieces()[ChessSquare::from(square).get_index() as usize].map(PyPiece::from)
+    }
+
+    fn legal_moves(&self) -> Vec<PyMove> {
+        self.board.legal_moves().into_iter().map(|chess_move| PyMove { chess_move }).collect()
+    }
+
+    // PLAYS THE MOVE AND RETURNS ITS SAN
+    fn push(&mut self, chess_move: PyMove) -> PyResult<String> {
+        self.board.play(chess_move.chess_move).map(|outcome| outcome.get_san().to_string()).map_err(value_error)
+    }
+
+    fn push_san(&mut self, san: &str) -> PyResult<PyMove> {
+        let (from, to, promotion) = self.board.parse_san(san).map_err(value_error)?;
+        let chess_move = Move::new(from, to, promotion);
+
+        self.board.play(chess_move).map_err(value_error)?;
+
+        Ok(PyMove { chess_move })
+    }
+
+    fn push_uci(&mut self, uci: &str) -> PyResult<PyMove> {
+        let (from, to, promotion) = self.board.parse_uci(uci).map_err(value_error)?;
+        let chess_move = Move::new(from, to, promotion);
+
+        self.board.play(chess_move).map_err(value_error)?;
+
+        Ok(PyMove { chess_move })
+    }
+
+    fn san(&self, chess_move: PyMove) -> PyResult<String> {
+        let chess_move = chess_move.chess_move;
+
+        self.board.to_san(chess_move.get_from(), chess_move.get_to(), chess_move.get_promotion()).map_err(value_error)
+    }
+
+    fn is_check(&self) -> bool {
+        self.board.is_check()
+    }
+
+    fn is_checkmate(&self) -> bool {
+        matches!(self.board.status(), GameStatus::Checkmate(_))
+    }
+
+    fn is_game_over(&self) -> bool {
+        self.board.status().is_over()
+    }
+
+    fn status(&self) -> String {
+        format!("{}", self.board.status())
+    }
+
+    fn perft(&self, depth: u32) -> u64 {
+        self.board.perft(depth)
+    }
+
+    fn zobrist_hash(&self) -> u64 {
+        self.board.hash()
+    }
+
+    fn __eq__(&self, other: &PyBoard) -> bool {
+        self.board.to_fen() == other.board.to_fen()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Board('{}')", self.board.to_fen())
+    }
+}
+
+#[pymodule]
+fn chess_engine(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyColor>()?;
+    module.add_class::<PyRole>()?;
+    module.add_class::<PySquare>()?;
+    module.add_class::<PyPiece>()?;
+    module.add_class::<PyMove>()?;
+    module.add_class::<PyBoard>()?;
+
+    Ok(())
+}
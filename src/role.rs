@@ -1,67 +1,18 @@
 use std::fmt;
-use std::mem;
 
+use crate::bitboard;
+use crate::bitboard::Bitboards;
 use crate::color::Color;
-use crate::direction::Direction;
-use crate::square::Square;
-use crate::piece::Piece;
+use crate::comp::ChessRole;
 
-enum Move {
-    Single,
-    Multi
-}
-
-fn get_possible_standard_moves(board: [Square; 64], origin_file: u8, origin_rank: u8, active_color: Color, paths: Vec<Vec<Direction>>, move_type: Move) -> Vec<Square> {
-    let mut possible_moves: Vec<Square> = Vec::new();
-
-    let origin_square = board[(origin_rank * 8 + origin_file) as usize];
-
-    for directions in paths {
-        let mut current_square = origin_square;
-
-        loop {
-            let mut next_square: Option<Square> = None;
-
-            for direction in &directions {
-                if next_square.is_some() {
-                    next_square = next_square.unwrap().next(direction, board);
-                } else {
-                    next_square = current_square.next(direction, board);
-                }
-            }
-
-            if next_square.is_some() {
-                current_square = next_square.unwrap();
-            } else {
-                break;
-            }
-
-            let occuping_piece = current_square.get_piece();
-
-            if occuping_piece.is_none() || (occuping_piece.is_some() && active_color != occuping_piece.unwrap().get_color()) {
-                possible_moves.push(current_square);
-            }
-
-            if occuping_piece.is_some() {
-                break;
-            }
-
-            let _break: bool;
-            match move_type {
-                Move::Single => _break = true,
-                _ => _break = false,
-            }
-
-            if _break {
-                break;
-            }
-        }
+fn home_rank(color: Color) -> u8 {
+    match color {
+        Color::White => 0,
+        Color::Black => 7,
     }
-
-    possible_moves
 }
 
-#[derive(Copy, Clone, Eq, Hash, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 pub enum Role {
     King,
     Queen,
@@ -72,112 +23,54 @@ pub enum Role {
 }
 
 impl Role {
-    pub fn is_position_checked(&self, board: [Square; 64], current_file: u8, current_rank: u8, active_color: Color, has_moved: bool) -> bool {
-        for i in 0..board.len() {
-            let square = board[i];
-            let _piece = square.get_piece();
-
-            if _piece.is_some() {
-                let piece = _piece.unwrap();
-
-                if piece.get_color() != active_color {
-                    if piece.get_role() != Role::King {
-                        for square in piece.get_role()._get_possible_moves(board, piece.get_file(), piece.get_rank(), piece.get_color(), has_moved) {
-                            if square.get_file() == current_file && square.get_rank() == current_rank {
-                                return true;
-                            }
-                        }
-                    } else {
-                        for direction in vec![
-                            Direction::UpLeft,
-                            Direction::Up,
-                            Direction::UpRight,
-                            Direction::Right,
-                            Direction::DownRight,
-                            Direction::Down,
-                            Direction::DownLeft,
-                            Direction::Left] {
-                            let next_square = square.next(&direction, board);
-
-                            if next_square.is_some() && next_square.unwrap() == square {
-                                return true;
-                            }
-                        }
-                    }
-                }
-            }
+    // KINGS ARE NEVER TRADED SO THEY ARE WORTH NOTHING IN EXCHANGES
+    pub fn get_value(&self) -> i32 {
+        match self {
+            Role::Pawn => 100,
+            Role::Knight => 320,
+            Role::Bichop => 330,
+            Role::Rook => 500,
+            Role::Queen => 900,
+            Role::King => 0,
         }
-
-        return false;
     }
 
-    fn _get_possible_moves(&self, board: [Square; 64], current_file: u8, current_rank: u8, active_color: Color, has_moved: bool) -> Vec<Square> {
+    pub fn get_attacks(&self, square: u8, active_color: Color, occupied: u64) -> u64 {
+        match self {
+            Role::King => bitboard::king_attacks(square),
+            Role::Queen => bitboard::queen_attacks(square, occupied),
+            Role::Bichop => bitboard::bishop_attacks(square, occupied),
+            Role::Knight => bitboard::knight_attacks(square),
+            Role::Rook => bitboard::rook_attacks(square, occupied),
+            Role::Pawn => bitboard::pawn_attacks(active_color, square),
+        }
+    }
 
-        let all_single_directions: Vec<Vec<Direction>> = vec![
-            vec![Direction::Up],
-            vec![Direction::UpLeft],
-            vec![Direction::UpRight],
-            vec![Direction::Down],
-            vec![Direction::DownLeft],
-            vec![Direction::DownRight],
-            vec![Direction::Left],
-            vec![Direction::Right]
-        ];
+    pub fn get_pseudo_legal_moves(&self, board: &Bitboards, square: u8, active_color: Color) -> u64 {
+        let own = board.get_color(active_color);
+        let enemy = board.get_color(active_color.opposite());
+        let occupied = own | enemy;
 
         match self {
             Role::King => {
-                let mut possible_moves: Vec<Square> = Vec::new();
-
-                let in_check = self.is_position_checked(board, current_file, current_rank, active_color, has_moved);
-
-                let mut standard_moves = get_possible_standard_moves(board, current_file, current_rank, active_color, all_single_directions, Move::Single);
-                possible_moves.append(&mut standard_moves);
+                let mut possible_moves = self.get_attacks(square, active_color, occupied) & !own;
 
                 // CASTLING
-                if !in_check && !has_moved {
-                    let check_color_rank: u8;
-                    match active_color {
-                        Color::White => check_color_rank = 0,
-                        _ => check_color_rank = 7
-                    }
-
-                    if current_rank == check_color_rank {
-                        // QUEENSIDE
-                        let mut castling_allowed = true;
+                let rank = home_rank(active_color);
+                let castling = board.get_castling_rights();
 
-                        for _file in 1..current_file {
-                            if board[(current_rank * 8 + _file) as usize].get_piece().is_some() {
-                                castling_allowed = false;
-                            }
-                        }
+                if square == rank * 8 + 4 && !board.is_attacked(square, active_color.opposite()) {
+                    // QUEENSIDE AND KINGSIDE
+                    for &(rook_file, target_file, allowed) in [(0, 2, castling.is_queenside_allowed(active_color)), (7, 6, castling.is_kingside_allowed(active_color))].iter() {
+                        let rook_square = rank * 8 + rook_file;
 
-                        let mut involved_rook = board[(current_rank * 8 + 0) as usize].get_piece();
+                        let between = if rook_square < square { (rook_square + 1)..square } else { (square + 1)..rook_square };
+                        let path_clear = between.into_iter().all(|_square| occupied & bitboard::bit(_square) == 0);
 
-                        if involved_rook.is_none() || (involved_rook.is_some() && involved_rook.unwrap().has_moved()) {
-                            castling_allowed = false;
-                        }
+                        let rook_ready = board.get_piece(rook_square) == Some((active_color, Role::Rook));
 
-                        if castling_allowed {
-                            possible_moves.push(board[(current_rank * 8 + 2) as usize])
-                        }
-
-                        // KINGSIDE
-                        castling_allowed = true;
-
-                        for _file in (current_file + 1)..8 {
-                            if board[(current_rank * 8 + _file) as usize].get_piece().is_some() {
-                                castling_allowed = false;
-                            }
-                        }
-
-                        involved_rook = board[(current_rank * 8 + 7) as usize].get_piece();
-
-                        if involved_rook.is_none() || (involved_rook.is_some() && involved_rook.unwrap().has_moved()) {
-                            castling_allowed = false;
-                        }
-
-                        if castling_allowed {
-                            possible_moves.push(board[(current_rank * 8 + 2) as usize])
+                        if allowed && path_clear && rook_ready {
+                            possible_moves |= bitboard::bit(rank * 8 + target_file);
                         }
                     }
                 }
@@ -185,219 +78,95 @@ impl Role {
                 possible_moves
             },
 
-            Role::Queen => get_possible_standard_moves(board, current_file, current_rank, active_color, all_single_directions, Move::Multi),
-
-            Role::Bichop => {
-                get_possible_standard_moves(board, current_file, current_rank, active_color, vec![
-                    vec![Direction::UpLeft],
-                    vec![Direction::UpRight],
-                    vec![Direction::DownLeft],
-                    vec![Direction::DownRight]
-                    ], Move::Multi)
-            },
-
-            Role::Knight => {
-                get_possible_standard_moves(board, current_file, current_rank, active_color, vec![
-                    vec![Direction::UpLeft, Direction::Up],
-                    vec![Direction::UpRight, Direction::Up],
-                    vec![Direction::DownLeft, Direction::Down],
-                    vec![Direction::DownRight, Direction::Down],
-                    vec![Direction::Left, Direction::UpLeft],
-                    vec![Direction::Left, Direction::DownLeft],
-                    vec![Direction::Right, Direction::UpRight],
-                    vec![Direction::Right, Direction::DownRight]
-                ], Move::Single)
-            },
-
-            Role::Rook => {
-                get_possible_standard_moves(board, current_file, current_rank, active_color, vec![
-                    vec![Direction::Up],
-                    vec![Direction::Down],
-                    vec![Direction::Left],
-                    vec![Direction::Right]
-                ], Move::Multi)
-            },
-
-            _ => {
-                let mut possible_moves: Vec<Square> = Vec::new();
-
-                let multiplier: i8;
-                let catch_directions: Vec<Direction>;
-                let en_passent_directions: Vec<Direction>;
-
-                match active_color {
-                    Color::White => {
-                        multiplier = 1;
-                        catch_directions = vec![Direction::UpLeft, Direction::UpRight];
-                        en_passent_directions = vec![Direction::DownLeft, Direction::DownRight];
-                    },
-                    _ => {
-                        multiplier = -1;
-                        catch_directions = vec![Direction::DownLeft, Direction::DownRight];
-                        en_passent_directions = vec![Direction::UpLeft, Direction::UpRight];
-                    }
-                }
-
-                // 2 STEP INITIAL MOVE
-                if current_rank == 1 || current_rank == 6 {
-                    possible_moves.push(board[(((current_rank as i8) + (2 * multiplier)) * 8 + (current_file as i8)) as usize]);
-                }
-
-                let current_square = board[(current_rank * 8 + current_file) as usize];
-
-                // CAPTURE MOVE
-                for direction in catch_directions {
-                    let _next_square = current_square.next(&direction, board);
+            Role::Pawn => {
+                let mut possible_moves = 0;
 
-                    if _next_square.is_some() {
-                        let next_square = _next_square.unwrap();
+                let (forward, start_rank, en_passent_rank): (i8, u8, u8) = match active_color {
+                    Color::White => (8, 1, 5),
+                    Color::Black => (-8, 6, 2),
+                };
 
-                        if next_square.get_piece().is_some() {
-                            possible_moves.push(board[(next_square.get_rank() * 8 + next_square.get_file()) as usize]);
-                        }
-                    }
-                }
-
-                // EN PASSENT
-                if current_square.is_en_passent_enabled() {
-                    for direction in en_passent_directions {
-                        let _next_square = current_square.next(&direction, board);
+                let single_square = square as i8 + forward;
 
-                        if _next_square.is_some() {
-                            let next_square = _next_square.unwrap();
+                if (0..64).contains(&single_square) && occupied & bitboard::bit(single_square as u8) == 0 {
+                    // SINGLE MOVE
+                    possible_moves |= bitboard::bit(single_square as u8);
 
-                            if next_square.get_piece().is_some() {
-                                possible_moves.push(board[(next_square.get_rank() * 8 + next_square.get_file()) as usize]);
+                    // 2 STEP INITIAL MOVE
+                    let double_square = single_square + forward;
 
-                                break;
-                            }
-                        }
+                    if square / 8 == start_rank && occupied & bitboard::bit(double_square as u8) == 0 {
+                        possible_moves |= bitboard::bit(double_square as u8);
                     }
                 }
 
-                // SINGLE MOVE
-                possible_moves.push(board[(((current_rank as i8) + multiplier) * 8 + (current_file as i8)) as usize]);
+                // CAPTURE MOVE, EN PASSENT TARGETS ARE MARKED ON THE SQUARE PASSED OVER
+                let en_passent = match board.get_en_passent() {
+                    Some(_square) if _square / 8 == en_passent_rank => bitboard::bit(_square),
+                    _ => 0,
+                };
 
-                possible_moves
+                possible_moves | (self.get_attacks(square, active_color, occupied) & (enemy | en_passent))
             },
+
+            _ => self.get_attacks(square, active_color, occupied) & !own,
         }
     }
 
-    pub fn get_possible_moves(&self, board: [Square; 64], current_file: u8, current_rank: u8, active_color: Color, has_moved: bool) -> Vec<Square> {
-        let mut output_moves: Vec<Square> = Vec::new();
-
-        let possible_moves = self._get_possible_moves(board, current_file, current_rank, active_color, has_moved);
+    pub fn get_possible_moves(&self, board: &Bitboards, square: u8, active_color: Color) -> u64 {
+        let mut output_moves = 0;
 
-        for i in 0..possible_moves.len() {
-            let board_copy: [Square; 64] = board.clone();
-
-            let move_test_file = possible_moves[i].get_file();
-            let move_test_rank = possible_moves[i].get_rank();
-
-            let mut castling_possible = true;
-
-            // CASTLING
-            if self == &Role::King && ((move_test_file as i8) - (current_file as i8)).abs() == 2 {
-                if !(((move_test_file as i8) - (current_file as i8) < 0 && !self.is_position_checked(board_copy, move_test_file + 1, move_test_rank, active_color, has_moved)) ||
-                    ((move_test_file as i8) - (current_file as i8) > 0 && !self.is_position_checked(board_copy, move_test_file - 1, move_test_rank, active_color, has_moved))) {
-                    castling_possible = false;
-                }
+        for target in bitboard::squares(self.get_pseudo_legal_moves(board, square, active_color)) {
+            // CASTLING MAY NOT PASS THROUGH AN ATTACKED SQUARE
+            if self == &Role::King && ((target as i8) - (square as i8)).abs() == 2 && board.is_attacked((square + target) / 2, active_color.opposite()) {
+                continue;
             }
 
-            let mut move_test_board = [board[0]; 64];
+            let mut move_test_board = *board;
+            self.make_move(&mut move_test_board, square, target, active_color);
 
-            for j in 1..move_test_board.len() {
-                move_test_board[j] = board[j];
-            }
-
-            move_test_board[(move_test_rank * 8 + move_test_file) as usize].set_piece(move_test_board[(current_rank * 8 + current_file) as usize].get_piece());
-            move_test_board[(current_rank * 8 + current_file) as usize].set_piece(None);
-
-            for j in 0..move_test_board.len() {
-                let _piece = move_test_board[j].get_piece();
-
-                if _piece.is_some() {
-                    let piece = _piece.unwrap();
-
-                    if piece.get_color() == active_color && piece.get_role() == Role::King {
-                        if castling_possible && !self.is_position_checked(board_copy, piece.get_file(), piece.get_rank(), active_color, has_moved) {
-                            output_moves.push(possible_moves[i]);
-                        }
-
-                        break;
-                    }
-                }
+            if !move_test_board.is_checked(active_color) {
+                output_moves |= bitboard::bit(target);
             }
         }
 
         output_moves
     }
 
-    pub fn move_to(&self, mut board: [Square; 64], target_file: u8, target_rank: u8, piece: Piece) -> (Result<Option<Piece>, String>, [Square; 64]) {
-
-        let current_file = piece.get_file();
-        let current_rank = piece.get_rank();
-        let active_color = piece.get_color();
+    pub fn make_move(&self, board: &mut Bitboards, square: u8, target: u8, active_color: Color) -> Option<(Color, Role)> {
+        let (current_file, current_rank) = (square % 8, square / 8);
+        let target_file = target % 8;
 
-        for possible_move in self.get_possible_moves(board, current_file, current_rank, active_color, piece.has_moved()) {
-            if possible_move.get_file() == target_file && possible_move.get_rank() == target_rank {
-                // CASTLING
-                if self == &Role::King && ((target_file as i8) - (piece.get_file() as i8)).abs() == 2 {
-                    let rook_file: u8;
-                    let rook_target_file: u8;
-
-                    if (target_file as i8) - (piece.get_file() as i8) < 0 {
-                        rook_file = 0;
-                        rook_target_file = 3;
-                    } else {
-                        rook_file = 7;
-                        rook_target_file = 5;
-                    }
+        // CASTLING
+        if self == &Role::King && ((target_file as i8) - (current_file as i8)).abs() == 2 {
+            let (rook_file, rook_target_file) = if target_file < current_file { (0, 3) } else { (7, 5) };
 
-                    if !board[(target_rank * 8 + rook_file) as usize].get_piece().unwrap().move_to(board, rook_target_file, target_rank).0.is_ok() {
-                        return (Err("Failed to move rook in context of castling!".to_string()), board)
-                    }
-                }
+            board.set_piece(current_rank * 8 + rook_file, None);
+            board.set_piece(current_rank * 8 + rook_target_file, Some((active_color, Role::Rook)));
+        }
 
-                else if self == &Role::Pawn && (target_rank - 2 == current_rank || target_rank + 2 == current_rank) {
-                    // SET EN PASSENT MARKERS
-                    match active_color {
-                        Color::White => {
-                            board[((target_rank - 1) * 8 + current_file + 1) as usize].enable_en_passent(true);
-                            board[((target_rank - 1) * 8 + current_file - 1) as usize].enable_en_passent(true);
-                        },
-                        Color::Black => {
-                            board[((target_rank + 1) * 8 + current_file + 1) as usize].enable_en_passent(true);
-                            board[((target_rank + 1) * 8 + current_file - 1) as usize].enable_en_passent(true);
-                        }
-                    }
-                }
+        // EN PASSENT CAPTURE
+        let mut en_passent_captured = None;
 
-                // REMOVE OLD EN PASSENT MARKERS
-                for _file in 0..8 {
-                    match piece.get_color() {
-                        Color::White => board[(6 * 8 + _file) as usize].enable_en_passent(false),
-                        Color::Black => board[(3 * 8 + _file) as usize].enable_en_passent(false),
-                    }
-                }
+        if self == &Role::Pawn && target_file != current_file && board.get_en_passent() == Some(target) {
+            en_passent_captured = board.set_piece(current_rank * 8 + target_file, None);
+        }
 
-                // REMOVE OLD PIECE REFERENCE
-                board[(piece.get_rank() * 8 + piece.get_file()) as usize].set_piece(None);
+        // SET EN PASSENT MARKER ON THE SQUARE PASSED OVER, REMOVING THE OLD ONE
+        if self == &Role::Pawn && ((target as i8) - (square as i8)).abs() == 16 {
+            board.set_en_passent(Some((square + target) / 2));
+        } else {
+            board.set_en_passent(None);
+        }
 
-                // SET NEW PIECE REFERENCE
-                let captured_piece = board[(target_rank * 8 + target_file) as usize].set_piece(Some(piece));
+        board.set_piece(square, None);
 
-                return (Ok(captured_piece), board);
-            }
-        }
+        let captured_piece = board.set_piece(target, Some((active_color, *self)));
 
-        return (Err("Target square is not a posssible move for current role.".to_string()), board);
-    }
-}
+        board.remove_castling_rights(square);
+        board.remove_castling_rights(target);
 
-impl PartialEq for Role {
-    fn eq(&self, other: &Self) -> bool {
-        mem::discriminant(self) == mem::discriminant(other)
+        captured_piece.or(en_passent_captured)
     }
 }
 
@@ -415,7 +184,71 @@ impl From<String> for Role {
             "Bichop" => Role::Bichop,
             "Knight" => Role::Knight,
             "Rook" => Role::Rook,
-            "Pawn" | _ => Role::Pawn,
+            _ => Role::Pawn,
         }
     }
 }
+
+impl From<ChessRole> for Role {
+    fn from(role: ChessRole) -> Self {
+        match role {
+            ChessRole::King => Role::King,
+            ChessRole::Queen => Role::Queen,
+            ChessRole::Bichop => Role::Bichop,
+            ChessRole::Knight => Role::Knight,
+            ChessRole::Rook => Role::Rook,
+            ChessRole::Pawn => Role::Pawn,
+        }
+    }
+}
+
+impl From<Role> for ChessRole {
+    fn from(role: Role) -> Self {
+        match role {
+            Role::King => ChessRole::King,
+            Role::Queen => ChessRole::Queen,
+            Role::Bichop => ChessRole::Bichop,
+            Role::Knight => ChessRole::Knight,
+            Role::Rook => ChessRole::Rook,
+            Role::Pawn => ChessRole::Pawn,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bitboard;
+    use crate::bitboard::Bitboards;
+    use crate::color::Color;
+    use crate::role::Role;
+
+    #[test]
+    fn get_possible_moves_pinned() {
+        let mut board = Bitboards::new();
+
+        board.set_piece(4, Some((Color::White, Role::King)));
+        board.set_piece(12, Some((Color::White, Role::Knight)));
+        board.set_piece(60, Some((Color::Black, Role::Rook)));
+
+        assert_eq!(Role::Knight.get_possible_moves(&board, 12, Color::White), 0);
+        assert_eq!(Role::King.get_possible_moves(&board, 4, Color::White), bitboard::bit(3) | bitboard::bit(5) | bitboard::bit(11) | bitboard::bit(13));
+    }
+
+    #[test]
+    fn make_move_en_passent() {
+        let mut board = Bitboards::new();
+
+        board.set_piece(36, Some((Color::White, Role::Pawn)));
+        board.set_piece(51, Some((Color::Black, Role::Pawn)));
+
+        assert_eq!(Role::Pawn.make_move(&mut board, 51, 35, Color::Black), None);
+        assert_eq!(board.get_en_passent(), Some(43));
+
+        assert_ne!(Role::Pawn.get_possible_moves(&board, 36, Color::White) & bitboard::bit(43), 0);
+        assert_eq!(Role::Pawn.make_move(&mut board, 36, 43, Color::White), Some((Color::Black, Role::Pawn)));
+        assert_eq!(board.get_piece(35), None);
+        assert_eq!(board.get_en_passent(), None);
+
+        assert_eq!(Role::Pawn.get_possible_moves(&board, 43, Color::White) & bitboard::bit(59), 0);
+    }
+}
@@ -0,0 +1,410 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::board::Board;
+use crate::color::Color;
+use crate::comp::ChessPiece;
+use crate::comp::ChessRole;
+use crate::comp::ChessSquare;
+use crate::comp::Move;
+
+/*
+ * Public Declarations:
+ * - enum SanError
+ */
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum SanError {
+    InvalidSyntax(String),
+    IllegalMove(String),
+    AmbiguousMove(String),
+}
+
+impl fmt::Display for SanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SanError::InvalidSyntax(san) => write!(f, "\"{}\" is not a valid SAN move.", san),
+            SanError::IllegalMove(san) => write!(f, "Move \"{}\" is not legal in the current position.", san),
+            SanError::AmbiguousMove(san) => write!(f, "Move \"{}\" matches more than one piece.", san),
+        }
+    }
+}
+
+impl Error for SanError {}
+
+// ---
+
+pub(crate) fn role_to_letter(role: ChessRole) -> &'static str {
+    match role {
+        ChessRole::King => "K",
+        ChessRole::Queen => "Q",
+        ChessRole::Bichop => "B",
+        ChessRole::Knight => "N",
+        ChessRole::Rook => "R",
+        ChessRole::Pawn => "",
+    }
+}
+
+pub(crate) fn letter_to_role(letter: char) -> Option<ChessRole> {
+    match letter {
+        'K' => Some(ChessRole::King),
+        'Q' => Some(ChessRole::Queen),
+        'B' => Some(ChessRole::Bichop),
+        'N' => Some(ChessRole::Knight),
+        'R' => Some(ChessRole::Rook),
+        _ => None,
+    }
+}
+
+fn promotion_letter_to_role(letter: char) -> Option<ChessRole> {
+    match letter_to_role(letter) {
+        Some(ChessRole::King) | None => None,
+        role => role,
+    }
+}
+
+// THE SAN OF A MOVE WITHOUT THE CHECK OR MATE SUFFIX
+pub(crate) fn format_move(board: &Board, from: ChessSquare, to: ChessSquare, promotion: Option<ChessRole>) -> Result<String, SanError> {
+    let illegal = || SanError::IllegalMove(format!("{}{}", from, to));
+
+    let pieces = board.get_active_pieces();
+
+    let piece = match pieces[from.get_index() as usize] {
+        Some(piece) if piece.get_color() == board.get_active_color() => piece,
+        _ => return Err(illegal()),
+    };
+
+    if !board.get_possible_moves(from).unwrap_or_default().iter().any(|chess_move| chess_move.get_to() == to) {
+        return Err(illegal());
+    }
+
+    let mut san = String::new();
+
+    if piece.get_role() == ChessRole::King && (to.get_file() as i8 - from.get_file() as i8).abs() == 2 {
+        san.push_str(if to.get_file() > from.get_file() { "O-O" } else { "O-O-O" });
+    } else {
+        let is_capture = pieces[to.get_index() as usize].is_some() ||
+            (piece.get_role() == ChessRole::Pawn && from.get_file() != to.get_file());
+
+        san.push_str(role_to_letter(piece.get_role()));
+
+        if piece.get_role() == ChessRole::Pawn {
+            if is_capture {
+                san.push((b'a' + from.get_file()) as char);
+            }
+        } else {
+            // DISAMBIGUATE BETWEEN PIECES OF THE SAME KIND REACHING THE SAME SQUARE
+            let rivals: Vec<ChessSquare> = (0..64u8)
+                .filter(|&i| i != from.get_index() && pieces[i as usize] == Some(piece))
+                .map(ChessSquare::from_index)
+                .filter(|&square| board.get_possible_moves(square).unwrap_or_default().iter().any(|chess_move| chess_move.get_to() == to))
+                .collect();
+
+            if !rivals.is_empty() {
+                if rivals.iter().all(|square| square.get_file() != from.get_file()) {
+                    san.push((b'a' + from.get_file()) as char);
+                } else if rivals.iter().all(|square| square.get_rank() != from.get_rank()) {
+                    san.push((b'1' + from.get_rank()) as char);
+                } else {
+                    san.push_str(&from.to_string());
+                }
+            }
+        }
+
+        if is_capture {
+            san.push('x');
+        }
+
+        san.push_str(&to.to_string());
+
+        let is_promotion = piece.get_role() == ChessRole::Pawn && (to.get_rank() == 0 || to.get_rank() == 7);
+
+        match promotion {
+            Some(ChessRole::King) | Some(ChessRole::Pawn) => return Err(illegal()),
+            Some(role) if is_promotion => {
+                san.push('=');
+                san.push_str(role_to_letter(role));
+            },
+            Some(_) => return Err(illegal()),
+            None if is_promotion => return Err(illegal()),
+            None => {},
+        }
+    }
+
+    Ok(san)
+}
+
+pub(crate) fn format(board: &Board, from: ChessSquare, to: ChessSquare, promotion: Option<ChessRole>) -> Result<String, SanError> {
+    let mut san = format_move(board, from, to, promotion)?;
+
+    // FORMAT_MOVE HAS ALREADY CHECKED THAT THE MOVE IS LEGAL
+    let mut after = board.clone();
+    after.move_piece_with_promotion(from, to, promotion).expect("Move was checked to be legal.");
+
+    if after.is_check() {
+        san.push(if after.has_legal_moves() { '+' } else { '#' });
+    }
+
+    Ok(san)
+}
+
+pub(crate) fn format_uci(board: &Board, from: ChessSquare, to: ChessSquare, promotion: Option<ChessRole>) -> Result<String, SanError> {
+    // VALIDATE THROUGH THE SAN FORMATTER SO BOTH NOTATIONS ACCEPT THE SAME MOVES
+    format(board, from, to, promotion)?;
+
+    Ok(Move::new(from, to, promotion).to_uci())
+}
+
+pub(crate) fn parse_uci(board: &Board, uci: &str) -> Result<(ChessSquare, ChessSquare, Option<ChessRole>), SanError> {
+    let invalid = || SanError::InvalidSyntax(uci.to_string());
+
+    if !uci.is_ascii() || (uci.len() != 4 && uci.len() != 5) {
+        return Err(invalid());
+    }
+
+    let from = uci[0..2].parse::<ChessSquare>().map_err(|_| invalid())?;
+    let to = uci[2..4].parse::<ChessSquare>().map_err(|_| invalid())?;
+
+    let promotion = match uci.chars().nth(4) {
+        Some(letter) => Some(promotion_letter_to_role(letter.to_ascii_uppercase()).ok_or_else(invalid)?),
+        None => None,
+    };
+
+    format(board, from, to, promotion).map_err(|_| SanError::IllegalMove(uci.to_string()))?;
+
+    Ok((from, to, promotion))
+}
+
+pub(crate) fn parse(board: &Board, san: &str) -> Result<(ChessSquare, ChessSquare, Option<ChessRole>), SanError> {
+    let invalid = || SanError::InvalidSyntax(san.to_string());
+    let illegal = || SanError::IllegalMove(san.to_string());
+
+    // SAN IS PLAIN ASCII, WHICH ALSO KEEPS THE BYTE SLICING BELOW ON CHARACTER BOUNDARIES
+    if !san.is_ascii() {
+        return Err(invalid());
+    }
+
+    let color = board.get_active_color();
+    let home_rank = match color {
+        Color::White => 0,
+        Color::Black => 7,
+    };
+
+    // STRIP ANNOTATIONS, CHECK MARKERS AND THE OPTIONAL EN PASSENT SUFFIX
+    let mut stripped = san.trim().trim_end_matches(['!', '?']).trim_end_matches(['+', '#']);
+
+    let en_passent = stripped.ends_with("e.p.");
+    if en_passent {
+        stripped = stripped[..stripped.len() - 4].trim_end();
+    }
+
+    let (role, from_file, from_rank, to, promotion) = match stripped {
+        "O-O" | "0-0" => (ChessRole::King, Some(4), Some(home_rank), ChessSquare::new(6, home_rank), None),
+        "O-O-O" | "0-0-0" => (ChessRole::King, Some(4), Some(home_rank), ChessSquare::new(2, home_rank), None),
+        _ => {
+            let mut rest = stripped;
+
+            // PROMOTION, WRITTEN EITHER AS "e8=Q" OR "e8Q"
+            let mut promotion: Option<ChessRole> = None;
+            if let Some(index) = rest.find('=') {
+                let mut letters = rest[index + 1..].chars();
+
+                promotion = Some(letters.next().and_then(promotion_letter_to_role).ok_or_else(invalid)?);
+
+                if letters.next().is_some() {
+                    return Err(invalid());
+                }
+
+                rest = &rest[..index];
+            } else if rest.len() > 2 && rest[..rest.len() - 1].ends_with(|c: char| c.is_ascii_digit()) {
+                if let Some(role) = rest.chars().last().and_then(promotion_letter_to_role) {
+                    promotion = Some(role);
+                    rest = &rest[..rest.len() - 1];
+                }
+            }
+
+            // ROLE
+            let role = match rest.chars().next().and_then(letter_to_role) {
+                Some(role) => {
+                    rest = &rest[1..];
+                    role
+                },
+                None => ChessRole::Pawn,
+            };
+
+            if rest.len() < 2 || !rest.is_char_boundary(rest.len() - 2) {
+                return Err(invalid());
+            }
+
+            let to = rest[rest.len() - 2..].parse::<ChessSquare>().map_err(|_| invalid())?;
+            rest = &rest[..rest.len() - 2];
+
+            let is_capture = rest.ends_with('x');
+            rest = rest.trim_end_matches('x');
+
+            // DISAMBIGUATION
+            let mut from_file: Option<u8> = None;
+            let mut from_rank: Option<u8> = None;
+
+            for c in rest.chars() {
+                match c {
+                    'a'..='h' if from_file.is_none() && from_rank.is_none() => from_file = Some(c as u8 - b'a'),
+                    '1'..='8' if from_rank.is_none() => from_rank = Some(c as u8 - b'1'),
+                    _ => return Err(invalid()),
+                }
+            }
+
+            // PAWN CAPTURES ALWAYS NAME THE ORIGIN FILE
+            if role == ChessRole::Pawn && is_capture != from_file.is_some() {
+                return Err(invalid());
+            }
+
+            (role, from_file, from_rank, to, promotion)
+        }
+    };
+
+    let is_castling = stripped.starts_with("O-O") || stripped.starts_with("0-0");
+    let is_promotion = role == ChessRole::Pawn && (to.get_rank() == 0 || to.get_rank() == 7);
+
+    if is_promotion != promotion.is_some() {
+        return Err(illegal());
+    }
+
+    let pieces = board.get_active_pieces();
+
+    let candidates: Vec<ChessSquare> = (0..64u8)
+        .filter(|&i| pieces[i as usize] == Some(ChessPiece::new(color, role)))
+        .map(ChessSquare::from_index)
+        .filter(|square| from_file.is_none() || from_file == Some(square.get_file()))
+        .filter(|square| from_rank.is_none() || from_rank == Some(square.get_rank()))
+        // CASTLING IS ONLY EXPRESSED THROUGH "O-O" AND "O-O-O"
+        .filter(|square| role != ChessRole::King || is_castling == ((square.get_file() as i8 - to.get_file() as i8).abs() == 2))
+        .filter(|&square| board.get_possible_moves(square).unwrap_or_default().iter().any(|chess_move| chess_move.get_to() == to))
+        .collect();
+
+    match candidates.len() {
+        0 => return Err(illegal()),
+        1 => {},
+        _ => return Err(SanError::AmbiguousMove(san.to_string())),
+    }
+
+    let from = candidates[0];
+
+    if en_passent && (role != ChessRole::Pawn || pieces[to.get_index() as usize].is_some()) {
+        return Err(illegal());
+    }
+
+    Ok((from, to, promotion))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::board::Board;
+    use crate::comp::ChessRole;
+    use crate::comp::ChessSquare;
+    use crate::san::SanError;
+
+    #[test]
+    fn to_san() {
+        let cases = [
+            ("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1", "e2", "e4", None, "e4"),
+            ("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1", "g1", "f3", None, "Nf3"),
+            ("4k3/8/8/8/8/8/8/1N2KN2 w - - 0 1", "b1", "d2", None, "Nbd2"),
+            ("4k3/8/8/8/8/8/8/1N2KN2 w - - 0 1", "f1", "d2", None, "Nfd2"),
+            ("4k3/8/8/R7/8/8/8/R3K3 w - - 0 1", "a1", "a3", None, "R1a3"),
+            ("4k3/8/8/8/8/Q7/8/Q1Q1K3 w - - 0 1", "a1", "b2", None, "Qa1b2"),
+            ("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1", "e1", "c1", None, "O-O-O"),
+            ("r3k2r/8/8/8/8/8/8/R3K2R b KQkq - 0 1", "e8", "g8", None, "O-O"),
+            ("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 2", "e5", "d6", None, "exd6"),
+            ("8/4P3/3k4/8/8/8/8/4K3 w - - 0 1", "e7", "e8", Some(ChessRole::Knight), "e8=N+"),
+            ("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1", "a1", "a8", None, "Ra8#"),
+        ];
+
+        for &(position, from, to, promotion, expected) in cases.iter() {
+            let board = Board::from_fen(position).unwrap();
+
+            assert_eq!(board.to_san(from.parse::<ChessSquare>().unwrap(), to.parse::<ChessSquare>().unwrap(), promotion).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn to_san_err() {
+        let board = Board::new();
+
+        assert_eq!(board.to_san(ChessSquare::E2, ChessSquare::E5, None).err(), Some(SanError::IllegalMove("e2e5".to_string())));
+        assert_eq!(board.to_san(ChessSquare::E7, ChessSquare::E5, None).err(), Some(SanError::IllegalMove("e7e5".to_string())));
+
+        let board = Board::from_fen("8/4P3/3k4/8/8/8/8/4K3 w - - 0 1").unwrap();
+
+        assert!(board.to_san(ChessSquare::E7, ChessSquare::E8, None).is_err());
+        assert!(board.to_san(ChessSquare::E7, ChessSquare::E8, Some(ChessRole::King)).is_err());
+    }
+
+    #[test]
+    fn parse_san() {
+        let cases = [
+            ("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1", "e4", "e2", "e4", None),
+            ("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1", "Nf3!?", "g1", "f3", None),
+            ("4k3/8/8/8/8/8/8/1N2KN2 w - - 0 1", "Nbd2", "b1", "d2", None),
+            ("4k3/8/8/8/8/8/8/1N2KN2 w - - 0 1", "Nf1d2", "f1", "d2", None),
+            ("4k3/8/8/R7/8/8/8/R3K3 w - - 0 1", "R5a3", "a5", "a3", None),
+            ("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1", "O-O-O", "e1", "c1", None),
+            ("r3k2r/8/8/8/8/8/8/R3K2R b KQkq - 0 1", "0-0+", "e8", "g8", None),
+            ("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 2", "exd6 e.p.", "e5", "d6", None),
+            ("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 2", "exd6e.p.", "e5", "d6", None),
+            ("8/4P3/3k4/8/8/8/8/4K3 w - - 0 1", "e8=N+", "e7", "e8", Some(ChessRole::Knight)),
+            ("8/4P3/3k4/8/8/8/8/4K3 w - - 0 1", "e8Q", "e7", "e8", Some(ChessRole::Queen)),
+            ("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1", "Ra8#", "a1", "a8", None),
+        ];
+
+        for &(position, san, from, to, promotion) in cases.iter() {
+            let board = Board::from_fen(position).unwrap();
+
+            assert_eq!(board.parse_san(san).unwrap(), (from.parse::<ChessSquare>().unwrap(), to.parse::<ChessSquare>().unwrap(), promotion));
+        }
+    }
+
+    #[test]
+    fn uci() {
+        let board = Board::new();
+
+        assert_eq!(board.parse_uci("g1f3").unwrap(), (ChessSquare::G1, ChessSquare::F3, None));
+        assert_eq!(board.to_uci(ChessSquare::G1, ChessSquare::F3, None).unwrap(), "g1f3");
+        assert_eq!(board.parse_uci("e2e5").err(), Some(SanError::IllegalMove("e2e5".to_string())));
+        assert_eq!(board.parse_uci("e2").err(), Some(SanError::InvalidSyntax("e2".to_string())));
+        assert_eq!(board.parse_uci("e2e4k").err(), Some(SanError::InvalidSyntax("e2e4k".to_string())));
+
+        let board = Board::from_fen("8/4P3/3k4/8/8/8/8/4K3 w - - 0 1").unwrap();
+
+        assert_eq!(board.parse_uci("e7e8n").unwrap(), (ChessSquare::E7, ChessSquare::E8, Some(ChessRole::Knight)));
+        assert_eq!(board.to_uci(ChessSquare::E7, ChessSquare::E8, Some(ChessRole::Queen)).unwrap(), "e7e8q");
+        assert_eq!(board.parse_uci("e7e8").err(), Some(SanError::IllegalMove("e7e8".to_string())));
+
+        let board = Board::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1").unwrap();
+
+        assert_eq!(board.parse_uci("e1g1").unwrap(), (ChessSquare::E1, ChessSquare::G1, None));
+    }
+
+    #[test]
+    fn parse_san_err() {
+        let board = Board::from_fen("4k3/8/8/8/8/8/8/1N2KN2 w - - 0 1").unwrap();
+
+        assert_eq!(board.parse_san("Nd2").err(), Some(SanError::AmbiguousMove("Nd2".to_string())));
+        assert_eq!(board.parse_san("Ne4").err(), Some(SanError::IllegalMove("Ne4".to_string())));
+        assert_eq!(board.parse_san("O-O").err(), Some(SanError::IllegalMove("O-O".to_string())));
+        assert_eq!(board.parse_san("Zz9").err(), Some(SanError::InvalidSyntax("Zz9".to_string())));
+        assert_eq!(board.parse_san("").err(), Some(SanError::InvalidSyntax("".to_string())));
+        assert_eq!(board.parse_san("Nf3é").err(), Some(SanError::InvalidSyntax("Nf3é".to_string())));
+        assert_eq!(board.parse_san("aé").err(), Some(SanError::InvalidSyntax("aé".to_string())));
+
+        let board = Board::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1").unwrap();
+
+        assert_eq!(board.parse_san("Kg1").err(), Some(SanError::IllegalMove("Kg1".to_string())));
+
+        let board = Board::from_fen("8/4P3/3k4/8/8/8/8/4K3 w - - 0 1").unwrap();
+
+        assert_eq!(board.parse_san("e8").err(), Some(SanError::IllegalMove("e8".to_string())));
+        assert_eq!(board.parse_san("e8=K").err(), Some(SanError::InvalidSyntax("e8=K".to_string())));
+        assert_eq!(board.parse_san("Kd2 e.p.").err(), Some(SanError::IllegalMove("Kd2 e.p.".to_string())));
+    }
+}
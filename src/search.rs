@@ -0,0 +1,471 @@
+use std::fmt;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use crate::board::Board;
+use crate::comp::Move;
+use crate::eval::EvalParams;
+use crate::role::Role;
+
+/*
+ * Public Declarations:
+ * - struct SearchLimits
+ * - enum Score
+ * - struct SearchResult
+ * - struct Searcher
+ */
+
+pub const MAX_DEPTH: u32 = 64;
+
+const MATE: i32 = 100_000;
+const INFINITY: i32 = 1_000_000;
+
+// LIMITS ARE ONLY CHECKED EVERY SO MANY NODES TO KEEP THE CLOCK OUT OF THE HOT PATH
+const CHECK_INTERVAL: u64 = 256;
+
+#[derive(Clone, Default, Debug)]
+pub struct SearchLimits {
+    pub depth: Option<u32>,
+    pub nodes: Option<u64>,
+    pub time: Option<Duration>,
+}
+
+// ---
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Score {
+    Centipawns(i32),
+    Mate(i32),
+}
+
+impl Score {
+    fn from_internal(score: i32) -> Score {
+        let mate_bound = MATE - MAX_DEPTH as i32 * 2;
+
+        if score >= mate_bound {
+            Score::Mate((MATE - score + 1) / 2)
+        } else if score <= -mate_bound {
+            Score::Mate(-(MATE + score + 1) / 2)
+        } else {
+            Score::Centipawns(score)
+        }
+    }
+}
+
+impl fmt::Display for Score {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Score::Centipawns(centipawns) => write!(f, "cp {}", centipawns),
+            Score::Mate(moves) => write!(f, "mate {}", moves),
+        }
+    }
+}
+
+// ---
+
+#[derive(Clone, Debug)]
+pub struct SearchResult {
+    best_move: Option<Move>,
+    score: Score,
+    pv: Vec<Move>,
+
+    depth: u32,
+    nodes: u64,
+    time: Duration,
+}
+
+impl SearchResult {
+    pub fn get_best_move(&self) -> Option<Move> {
+        self.best_move
+    }
+
+    pub fn get_score(&self) -> Score {
+        self.score
+    }
+
+    pub fn get_pv(&self) -> &[Move] {
+        &self.pv
+    }
+
+    pub fn get_depth(&self) -> u32 {
+        self.depth
+    }
+
+    pub fn get_nodes(&self) -> u64 {
+        self.nodes
+    }
+
+    pub fn get_time(&self) -> Duration {
+        self.time
+    }
+}
+
+// ---
+
+// ONLY USED TO ORDER MOVES, THE EVALUATION HAS ITS OWN VALUES
+fn captured_role(board: &Board, chess_move: &Move) -> Option<Role> {
+    let (from, to) = (chess_move.get_from(), chess_move.get_to());
+    let bitboards = board.get_bitboards();
+
+    match bitboards.get_piece(to.get_index()) {
+        Some((_, role)) => Some(role),
+        // EN PASSENT
+        None if bitboards.get_piece(from.get_index()).map(|(_, role)| role) == Some(Role::Pawn) && from.get_file() != to.get_file() => Some(Role::Pawn),
+        None => None,
+    }
+}
+
+fn order_moves(board: &Board, moves: &mut [Move], pv_move: Option<&Move>) {
+    let bitboards = board.get_bitboards();
+
+    // PV MOVE FIRST, THEN CAPTURES BY MOST VALUABLE VICTIM / LEAST VALUABLE ATTACKER
+    moves.sort_by_cached_key(|chess_move| {
+        if Some(chess_move) == pv_move {
+            return i32::MIN;
+        }
+
+        let from = chess_move.get_from();
+        let attacker = bitboards.get_piece(from.get_index()).map(|(_, role)| role.get_value()).unwrap_or(0);
+
+        let mut key = 0;
+
+        if let Some(victim) = captured_role(board, chess_move) {
+            key -= victim.get_value() * 10 - attacker / 10;
+        }
+
+        if let Some(role) = chess_move.get_promotion() {
+            key -= Role::from(role).get_value();
+        }
+
+        key
+    });
+}
+
+pub struct Searcher {
+    limits: SearchLimits,
+    params: EvalParams,
+    stop: Arc<AtomicBool>,
+
+    start: Instant,
+    nodes: u64,
+    aborted: bool,
+}
+
+impl Searcher {
+    pub fn new(limits: SearchLimits) -> Searcher {
+        Searcher {
+            limits,
+            params: EvalParams::default(),
+            stop: Arc::new(AtomicBool::new(false)),
+            start: Instant::now(),
+            nodes: 0,
+            aborted: false,
+        }
+    }
+
+    pub fn get_eval_params(&self) -> &EvalParams {
+        &self.params
+    }
+
+    pub fn set_eval_params(&mut self, params: EvalParams) {
+        self.params = params;
+    }
+
+    pub fn get_stop_flag(&self) -> Arc<AtomicBool> {
+        Arc::clone(&self.stop)
+    }
+
+    pub fn search(&mut self, board: &Board) -> SearchResult {
+        self.search_with(board, |_| {})
+    }
+
+    pub fn search_with<F: FnMut(&SearchResult)>(&mut self, board: &Board, mut on_iteration: F) -> SearchResult {
+        self.start = Instant::now();
+        self.nodes = 0;
+        self.aborted = false;
+
+        let root_moves = board.legal_moves();
+
+        let mut result = SearchResult {
+            best_move: None,
+            score: Score::Centipawns(0),
+            pv: Vec::new(),
+            depth: 0,
+            nodes: 0,
+            time: Duration::from_secs(0),
+        };
+
+        if root_moves.is_empty() {
+            if board.is_check() {
+                result.score = Score::Mate(0);
+            }
+
+            return result;
+        }
+
+        let max_depth = self.limits.depth.unwrap_or(MAX_DEPTH).clamp(1, MAX_DEPTH);
+
+        // THE SEARCH WORKS ON ONE BOARD, UNDOING EVERY MOVE IT TRIES
+        let mut board = board.clone();
+
+        for depth in 1..=max_depth {
+            let mut pv: Vec<Move> = Vec::new();
+            let previous_pv = result.pv.clone();
+
+            let score = self.negamax(&mut board, depth, 0, -INFINITY, INFINITY, &mut pv, &previous_pv);
+
+            if self.aborted {
+                // A PARTIAL FIRST ITERATION IS BETTER THAN NOTHING
+                if result.best_move.is_none() && !pv.is_empty() {
+                    result.best_move = pv.first().copied();
+                    result.pv = pv;
+                }
+
+                break;
+            }
+
+            result = SearchResult {
+                best_move: pv.first().copied(),
+                score: Score::from_internal(score),
+                pv,
+                depth,
+                nodes: self.nodes,
+                time: self.start.elapsed(),
+            };
+
+            on_iteration(&result);
+
+            // A DEEPER SEARCH CANNOT FIND A SHORTER MATE
+            if let Score::Mate(moves) = result.score {
+                if moves.unsigned_abs() * 2 <= depth {
+                    break;
+                }
+            }
+        }
+
+        if result.best_move.is_none() {
+            result.best_move = root_moves.first().copied();
+            result.pv = root_moves.into_iter().take(1).collect();
+        }
+
+        result.nodes = self.nodes;
+        result.time = self.start.elapsed();
+
+        result
+    }
+
+    fn should_stop(&mut self) -> bool {
+        if self.aborted {
+            return true;
+        }
+
+        if self.limits.nodes.is_some_and(|limit| self.nodes >= limit) {
+            self.aborted = true;
+        } else if self.nodes.is_multiple_of(CHECK_INTERVAL) {
+            self.aborted = self.stop.load(Ordering::Relaxed) || self.limits.time.is_some_and(|time| self.start.elapsed() >= time);
+        }
+
+        self.aborted
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn negamax(&mut self, board: &mut Board, depth: u32, ply: u32, mut alpha: i32, beta: i32, pv: &mut Vec<Move>, pv_hint: &[Move]) -> i32 {
+        if self.should_stop() {
+            return 0;
+        }
+
+        if depth == 0 {
+            return self.quiesce(board, alpha, beta);
+        }
+
+        self.nodes += 1;
+
+        if ply > 0 && board.get_halfmove_clock() >= 100 {
+            return 0;
+        }
+
+        let mut moves = board.legal_moves();
+
+        if moves.is_empty() {
+            return if board.is_check() { -MATE + ply as i32 } else { 0 };
+        }
+
+        order_moves(board, &mut moves, pv_hint.first());
+
+        let mut best = -INFINITY;
+
+        for chess_move in moves {
+            let undo = board.make_move_unchecked(chess_move);
+
+            let child_hint: &[Move] = if pv_hint.first() == Some(&chess_move) { &pv_hint[1..] } else { &[] };
+            let mut child_pv: Vec<Move> = Vec::new();
+
+            let score = -self.negamax(board, depth - 1, ply + 1, -beta, -alpha, &mut child_pv, child_hint);
+
+            board.unmake_move(chess_move, undo);
+
+            if self.aborted {
+                return 0;
+            }
+
+            if score > best {
+                best = score;
+            }
+
+            if score > alpha {
+                alpha = score;
+
+                pv.clear();
+                pv.push(chess_move);
+                pv.append(&mut child_pv);
+            }
+
+            if alpha >= beta {
+                break;
+            }
+        }
+
+        best
+    }
+
+    fn quiesce(&mut self, board: &mut Board, mut alpha: i32, beta: i32) -> i32 {
+        if self.should_stop() {
+            return 0;
+        }
+
+        self.nodes += 1;
+
+        let stand_pat = board.evaluate(&self.params);
+
+        if stand_pat >= beta {
+            return stand_pat;
+        }
+
+        if stand_pat > alpha {
+            alpha = stand_pat;
+        }
+
+        // ONLY CAPTURES AND PROMOTIONS SETTLE THE POSITION
+        let mut moves: Vec<Move> = board.legal_moves().into_iter()
+            .filter(|chess_move| chess_move.get_promotion().is_some() || captured_role(board, chess_move).is_some())
+            .collect();
+
+        order_moves(board, &mut moves, None);
+
+        for chess_move in moves {
+            let undo = board.make_move_unchecked(chess_move);
+
+            let score = -self.quiesce(board, -beta, -alpha);
+
+            board.unmake_move(chess_move, undo);
+
+            if self.aborted {
+                return 0;
+            }
+
+            if score >= beta {
+                return score;
+            }
+
+            if score > alpha {
+                alpha = score;
+            }
+        }
+
+        alpha
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use std::time::Instant;
+
+    use crate::board::Board;
+    use crate::comp::ChessSquare;
+    use crate::comp::Move;
+    use crate::search::Score;
+    use crate::search::SearchLimits;
+    use crate::search::Searcher;
+
+    fn limits(depth: Option<u32>, nodes: Option<u64>, time: Option<Duration>) -> SearchLimits {
+        SearchLimits { depth, nodes, time }
+    }
+
+    #[test]
+    fn mate_in_one() {
+        let board = Board::from_fen("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1").unwrap();
+        let result = Searcher::new(limits(Some(3), None, None)).search(&board);
+
+        assert_eq!(result.get_best_move(), Some(Move::new(ChessSquare::new(0, 0), ChessSquare::new(0, 7), None)));
+        assert_eq!(result.get_score(), Score::Mate(1));
+        assert_eq!(format!("{}", result.get_score()), "mate 1");
+    }
+
+    #[test]
+    fn mate_in_two() {
+        // 1. Kg6 Kg8 2. Rb8#
+        let board = Board::from_fen("7k/8/5K2/8/8/8/8/1R6 w - - 0 1").unwrap();
+        let result = Searcher::new(limits(Some(4), None, None)).search(&board);
+
+        assert_eq!(result.get_score(), Score::Mate(2));
+        assert_eq!(result.get_pv().len(), 3);
+        assert_eq!(result.get_best_move(), result.get_pv().first().copied());
+    }
+
+    #[test]
+    fn mated() {
+        let board = Board::from_fen("6k1/5ppp/8/8/8/8/r7/r5K1 w - - 0 1").unwrap();
+        let result = Searcher::new(limits(Some(2), None, None)).search(&board);
+
+        assert_eq!(result.get_best_move(), None);
+        assert_eq!(result.get_score(), Score::Mate(0));
+
+        let board = Board::from_fen("7k/8/6QK/8/8/8/8/8 b - - 0 1").unwrap();
+        let result = Searcher::new(limits(Some(2), None, None)).search(&board);
+
+        assert_eq!(result.get_best_move(), None);
+        assert_eq!(result.get_score(), Score::Centipawns(0));
+    }
+
+    #[test]
+    fn wins_material() {
+        let board = Board::from_fen("4k3/8/8/3q4/8/8/8/3RK3 w - - 0 1").unwrap();
+        let result = Searcher::new(limits(Some(2), None, None)).search(&board);
+
+        assert_eq!(result.get_best_move(), Some(Move::new(ChessSquare::new(3, 0), ChessSquare::new(3, 4), None)));
+        assert!(matches!(result.get_score(), Score::Centipawns(score) if score > 400));
+    }
+
+    #[test]
+    fn limits_nodes() {
+        let board = Board::new();
+        let result = Searcher::new(limits(None, Some(500), None)).search(&board);
+
+        assert!(result.get_best_move().is_some());
+        assert!(result.get_nodes() <= 500);
+    }
+
+    #[test]
+    fn limits_time() {
+        let board = Board::new();
+        let start = Instant::now();
+        let result = Searcher::new(limits(None, None, Some(Duration::from_millis(200)))).search(&board);
+
+        assert!(result.get_best_move().is_some());
+        assert!(start.elapsed() < Duration::from_secs(2));
+    }
+
+    #[test]
+    fn stop_flag() {
+        let board = Board::new();
+        let mut searcher = Searcher::new(SearchLimits::default());
+
+        searcher.get_stop_flag().store(true, std::sync::atomic::Ordering::Relaxed);
+
+        assert!(searcher.search(&board).get_best_move().is_some());
+    }
+}
@@ -0,0 +1,129 @@
+use crate::bitboard;
+use crate::bitboard::Bitboards;
+use crate::color::Color;
+use crate::role::Role;
+
+// CHEAPEST ATTACKER FIRST
+const EXCHANGE_ORDER: [Role; 6] = [Role::Pawn, Role::Knight, Role::Bichop, Role::Rook, Role::Queen, Role::King];
+
+pub(crate) fn attackers(board: &Bitboards, square: u8, color: Color) -> u64 {
+    let mut occupied = board.get_occupied();
+    let mut found = 0;
+
+    // LIFTING EVERY ATTACKER REVEALS THE SLIDERS LINED UP BEHIND IT
+    loop {
+        let attackers = board.attackers_to(square, occupied) & !found;
+
+        if attackers == 0 {
+            return found & board.get_color(color);
+        }
+
+        found |= attackers;
+        occupied &= !attackers;
+    }
+}
+
+fn least_valuable_attacker(board: &Bitboards, attackers: u64, color: Color) -> Option<(u8, Role)> {
+    EXCHANGE_ORDER.iter()
+        .find_map(|&role| bitboard::squares(attackers & board.get_pieces(color, role)).next().map(|square| (square, role)))
+}
+
+pub(crate) fn see(board: &Bitboards, from: u8, to: u8) -> i32 {
+    let (color, role) = match board.get_piece(from) {
+        Some(piece) => piece,
+        None => return 0,
+    };
+
+    let mut occupied = board.get_occupied() & !bitboard::bit(from);
+
+    // EN PASSENT TAKES A PAWN FROM BESIDE THE TARGET SQUARE
+    let captured = match board.get_piece(to) {
+        Some((_, captured)) => captured.get_value(),
+        None if role == Role::Pawn && from % 8 != to % 8 => {
+            occupied &= !bitboard::bit(from / 8 * 8 + to % 8);
+
+            Role::Pawn.get_value()
+        },
+        None => 0,
+    };
+
+    let mut gains = vec![captured];
+    let mut on_square = role.get_value();
+    let mut side = color.opposite();
+
+    loop {
+        let attackers = board.attackers_to(to, occupied) & board.get_color(side);
+
+        let (square, attacker) = match least_valuable_attacker(board, attackers, side) {
+            Some(attacker) => attacker,
+            None => break,
+        };
+
+        // A KING MAY ONLY RECAPTURE ON AN UNDEFENDED SQUARE
+        if attacker == Role::King && board.attackers_to(to, occupied & !bitboard::bit(square)) & board.get_color(side.opposite()) != 0 {
+            break;
+        }
+
+        gains.push(on_square - gains[gains.len() - 1]);
+
+        on_square = attacker.get_value();
+        occupied &= !bitboard::bit(square);
+        side = side.opposite();
+    }
+
+    // EITHER SIDE MAY STOP CAPTURING WHEN GOING ON WOULD LOSE MATERIAL
+    for index in (1..gains.len()).rev() {
+        gains[index - 1] = -(-gains[index - 1]).max(gains[index]);
+    }
+
+    gains[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::board::Board;
+    use crate::color::Color;
+    use crate::comp::ChessSquare;
+
+    #[test]
+    fn attackers() {
+        // DOUBLED ROOKS AND A QUEEN BEHIND A BICHOP
+        let board = Board::from_fen("4k3/8/8/3p4/8/1BN5/Q2R4/K2R4 w - - 0 1").unwrap();
+        let attackers = board.attackers_to(ChessSquare::new(3, 4), Color::White);
+
+        assert_eq!(attackers, [ChessSquare::new(3, 0), ChessSquare::new(0, 1), ChessSquare::new(3, 1), ChessSquare::new(1, 2), ChessSquare::new(2, 2)]);
+        assert_eq!(board.attackers_to(ChessSquare::new(3, 4), Color::Black), []);
+
+        // BLOCKERS THAT DO NOT ATTACK HIDE WHAT IS BEHIND THEM
+        let board = Board::from_fen("4k3/8/8/3p4/3P4/8/8/K2R4 w - - 0 1").unwrap();
+
+        assert_eq!(board.attackers_to(ChessSquare::new(3, 4), Color::White), []);
+        assert_eq!(board.attackers_to(ChessSquare::new(4, 3), Color::Black), [ChessSquare::new(3, 4)]);
+    }
+
+    #[test]
+    fn see() {
+        let positions = [
+            // FREE PAWN
+            ("1k1r4/1pp4p/p7/4p3/8/P5P1/1PP4P/2K1R3 w - - 0 1", (4, 0), (4, 4), 100),
+            // KNIGHT FOR PAWN, THEN THE EXCHANGES ON E5 STOP EARLY
+            ("1k1r3q/1ppn3p/p4b2/4p3/8/P2N2P1/1PP1R1BP/2K1Q3 w - - 0 1", (3, 2), (4, 4), -220),
+            // PAWN TAKES DEFENDED KNIGHT
+            ("4k3/8/4p3/3n4/4P3/8/8/4K3 w - - 0 1", (4, 3), (3, 4), 220),
+            // QUIET MOVE ONTO A SQUARE ATTACKED BY A PAWN
+            ("4k3/8/2p5/8/3N4/8/8/4K3 w - - 0 1", (3, 3), (1, 4), -320),
+            // THE KING CANNOT RECAPTURE ON A DEFENDED SQUARE
+            ("8/8/8/2k5/3p4/4Q3/1B6/4K3 w - - 0 1", (4, 2), (3, 3), 100),
+            ("8/8/8/2k5/3p4/4Q3/8/3RK3 w - - 0 1", (4, 2), (3, 3), 100),
+            ("8/8/8/2k5/3p4/4Q3/8/4K3 w - - 0 1", (4, 2), (3, 3), -800),
+            // EN PASSENT
+            ("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1", (4, 4), (3, 5), 100),
+        ];
+
+        for &(position, from, to, value) in positions.iter() {
+            let board = Board::from_fen(position).unwrap();
+
+            assert_eq!(board.see(ChessSquare::new(from.0, from.1), ChessSquare::new(to.0, to.1)), value, "{}", position);
+        }
+    }
+}
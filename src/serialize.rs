@@ -0,0 +1,280 @@
+use serde::de;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+
+use crate::board::Board;
+use crate::color::Color;
+use crate::comp::CastlingRights;
+use crate::comp::ChessPiece;
+use crate::comp::ChessRole;
+use crate::comp::ChessSquare;
+use crate::comp::Move;
+use crate::fen;
+
+/*
+ * Serde representations, only built with the serde feature:
+ * - Color as "w" or "b"
+ * - ChessRole as "K", "Q", "B", "N", "R" or "P"
+ * - ChessPiece as color and role, "wN"
+ * - ChessSquare as "e4"
+ * - Move as UCI, "e7e8q"
+ * - CastlingRights as the FEN field, "KQkq" or "-"
+ * - Board as its FEN and the hashes of earlier positions
+ */
+
+fn color_to_char(color: Color) -> char {
+    match color {
+        Color::White => 'w',
+        Color::Black => 'b',
+    }
+}
+
+fn char_to_color(c: char) -> Option<Color> {
+    match c {
+        'w' => Some(Color::White),
+        'b' => Some(Color::Black),
+        _ => None,
+    }
+}
+
+fn role_to_char(role: ChessRole) -> char {
+    fen::piece_to_char(ChessPiece::new(Color::White, role))
+}
+
+fn char_to_role(c: char) -> Option<ChessRole> {
+    match fen::char_to_piece(c) {
+        Some(piece) if piece.get_color() == Color::White => Some(piece.get_role()),
+        _ => None,
+    }
+}
+
+fn single_char(value: &str) -> Option<char> {
+    let mut chars = value.chars();
+
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
+
+// EVERY TYPE BUT THE BOARD IS A SHORT STRING
+fn deserialize_str<'de, D, T, F>(deserializer: D, expected: &str, parse: F) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    F: FnOnce(&str) -> Option<T>,
+{
+    let value = String::deserialize(deserializer)?;
+
+    parse(&value).ok_or_else(|| de::Error::invalid_value(de::Unexpected::Str(&value), &expected))
+}
+
+// ---
+
+impl Serialize for Color {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&color_to_char(*self).to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_str(deserializer, "\"w\" or \"b\"", |value| single_char(value).and_then(char_to_color))
+    }
+}
+
+impl Serialize for ChessRole {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&role_to_char(*self).to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ChessRole {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_str(deserializer, "a role letter like \"N\"", |value| single_char(value).and_then(char_to_role))
+    }
+}
+
+impl Serialize for ChessPiece {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("{}{}", color_to_char(self.get_color()), role_to_char(self.get_role())))
+    }
+}
+
+impl<'de> Deserialize<'de> for ChessPiece {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_str(deserializer, "a color and role like \"wN\"", |value| {
+            let mut chars = value.chars();
+
+            match (chars.next().and_then(char_to_color), chars.next().and_then(char_to_role), chars.next()) {
+                (Some(color), Some(role), None) => Some(ChessPiece::new(color, role)),
+                _ => None,
+            }
+        })
+    }
+}
+
+impl Serialize for ChessSquare {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ChessSquare {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_str(deserializer, "a square like \"e4\"", |value| value.parse().ok())
+    }
+}
+
+// ONLY FROM, TO AND PROMOTION ARE KEPT, THE DETAILS FROM MOVE GENERATION ARE NOT
+impl Serialize for Move {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_uci())
+    }
+}
+
+impl<'de> Deserialize<'de> for Move {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_str(deserializer, "a UCI move like \"e7e8q\"", |value| {
+            if !value.is_ascii() || (value.len() != 4 && value.len() != 5) {
+                return None;
+            }
+
+            let promotion = match value[4..].chars().next() {
+                Some(c) if c.is_ascii_lowercase() => Some(fen::char_to_piece(c)?.get_role()),
+                Some(_) => return None,
+                None => None,
+            };
+
+            Move::try_new(value[0..2].parse().ok()?, value[2..4].parse().ok()?, promotion).ok()
+        })
+    }
+}
+
+impl Serialize for CastlingRights {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&fen::castling_to_string(*self))
+    }
+}
+
+impl<'de> Deserialize<'de> for CastlingRights {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_str(deserializer, "a FEN castling field like \"KQkq\"", |value| fen::parse_castling(value).ok())
+    }
+}
+
+// ---
+
+// PROMOTION SETTINGS ARE PREFERENCES RATHER THAN PART OF THE GAME AND ARE NOT STORED
+#[derive(Serialize, Deserialize)]
+struct BoardState {
+    fen: String,
+    history: Vec<u64>,
+}
+
+impl Serialize for Board {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        BoardState { fen: self.to_fen(), history: self.get_history().to_vec() }.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Board {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let state = BoardState::deserialize(deserializer)?;
+        let mut board = Board::from_fen(&state.fen).map_err(de::Error::custom)?;
+
+        board.set_history(state.history);
+
+        Ok(board)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::board::Board;
+    use crate::color::Color;
+    use crate::comp::CastlingRights;
+    use crate::comp::ChessPiece;
+    use crate::comp::ChessRole;
+    use crate::comp::ChessSquare;
+    use crate::comp::Move;
+    use crate::status::GameStatus;
+
+    #[test]
+    fn json() {
+        assert_eq!(serde_json::to_string(&Color::Black).unwrap(), "\"b\"");
+        assert_eq!(serde_json::to_string(&ChessRole::Bichop).unwrap(), "\"B\"");
+        assert_eq!(serde_json::to_string(&ChessPiece::new(Color::White, ChessRole::Knight)).unwrap(), "\"wN\"");
+        assert_eq!(serde_json::to_string(&ChessSquare::new(4, 3)).unwrap(), "\"e4\"");
+        assert_eq!(serde_json::to_string(&Move::new(ChessSquare::new(4, 6), ChessSquare::new(4, 7), Some(ChessRole::Queen))).unwrap(), "\"e7e8q\"");
+        assert_eq!(serde_json::to_string(&CastlingRights::new(true, false, false, true)).unwrap(), "\"Kq\"");
+        assert_eq!(serde_json::to_string(&Board::new()).unwrap(), "{\"fen\":\"rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1\",\"history\":[]}");
+
+        assert_eq!(serde_json::from_str::<Color>("\"w\"").unwrap(), Color::White);
+        assert_eq!(serde_json::from_str::<ChessRole>("\"K\"").unwrap(), ChessRole::King);
+        assert_eq!(serde_json::from_str::<ChessPiece>("\"bP\"").unwrap(), ChessPiece::new(Color::Black, ChessRole::Pawn));
+        assert_eq!(serde_json::from_str::<ChessSquare>("\"h8\"").unwrap(), ChessSquare::new(7, 7));
+        assert_eq!(serde_json::from_str::<Move>("\"g1f3\"").unwrap(), Move::new(ChessSquare::new(6, 0), ChessSquare::new(5, 2), None));
+        assert_eq!(serde_json::from_str::<CastlingRights>("\"-\"").unwrap(), CastlingRights::none());
+    }
+
+    #[test]
+    fn json_err() {
+        assert!(serde_json::from_str::<Color>("\"white\"").is_err());
+        assert!(serde_json::from_str::<ChessRole>("\"n\"").is_err());
+        assert!(serde_json::from_str::<ChessPiece>("\"wNx\"").is_err());
+        assert!(serde_json::from_str::<ChessSquare>("\"i1\"").is_err());
+        assert!(serde_json::from_str::<Move>("\"e7e8k\"").is_err());
+        assert!(serde_json::from_str::<Move>("\"e7e8Q\"").is_err());
+        assert!(serde_json::from_str::<CastlingRights>("\"KK\"").is_err());
+        assert!(serde_json::from_str::<Board>("{\"fen\":\"8/8/8/8/8/8/8/8 w - - 0 1\",\"history\":[]}").is_err());
+        assert!(serde_json::from_str::<Board>("{\"fen\":\"rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1\"}").is_err());
+    }
+
+    // THE HISTORY KEEPS REPETITIONS FROM BEFORE THE ROUND TRIP
+    fn repeated_board() -> Board {
+        let mut board = Board::new();
+
+        for _ in 0..2 {
+            for &(from, to) in [((6, 0), (5, 2)), ((6, 7), (5, 5)), ((5, 2), (6, 0)), ((5, 5), (6, 7))].iter() {
+                board.play(Move::new(ChessSquare::new(from.0, from.1), ChessSquare::new(to.0, to.1), None)).unwrap();
+            }
+        }
+
+        board
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let board = repeated_board();
+        let restored: Board = serde_json::from_str(&serde_json::to_string(&board).unwrap()).unwrap();
+
+        assert_eq!(restored.to_fen(), board.to_fen());
+        assert_eq!(restored.hash(), board.hash());
+        assert_eq!(restored.status(), GameStatus::Repetition);
+
+        let pieces: Vec<Option<ChessPiece>> = serde_json::from_str(&serde_json::to_string(&board.get_active_pieces()).unwrap()).unwrap();
+
+        assert_eq!(pieces, board.get_active_pieces());
+    }
+
+    #[test]
+    fn binary_round_trip() {
+        let board = repeated_board();
+        let restored: Board = postcard::from_bytes(&postcard::to_stdvec(&board).unwrap()).unwrap();
+
+        assert_eq!(restored.to_fen(), board.to_fen());
+        assert_eq!(restored.status(), GameStatus::Repetition);
+
+        let moves = board.legal_moves();
+        let restored: Vec<Move> = postcard::from_bytes(&postcard::to_stdvec(&moves).unwrap()).unwrap();
+
+        assert_eq!(restored, moves);
+
+        let values = (Color::Black, ChessRole::Rook, ChessPiece::new(Color::White, ChessRole::Queen), ChessSquare::new(0, 7), CastlingRights::all());
+        let restored: (Color, ChessRole, ChessPiece, ChessSquare, CastlingRights) = postcard::from_bytes(&postcard::to_stdvec(&values).unwrap()).unwrap();
+
+        assert_eq!(restored, values);
+    }
+}
@@ -0,0 +1,161 @@
+use std::fmt;
+
+use crate::bitboard::Bitboards;
+use crate::board::Board;
+use crate::color::Color;
+use crate::role::Role;
+
+/*
+ * Public Declarations:
+ * - enum GameStatus
+ */
+
+const LIGHT_SQUARES: u64 = 0x55aa_55aa_55aa_55aa;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum GameStatus {
+    Ongoing,
+    // THE COLOR THAT DELIVERED MATE
+    Checkmate(Color),
+    Stalemate,
+    InsufficientMaterial,
+    // CLAIMABLE DRAWS, PLAY GOES ON UNTIL ONE IS CLAIMED
+    Repetition,
+    FiftyMove,
+    // AUTOMATIC DRAWS
+    FivefoldRepetition,
+    SeventyFiveMove,
+}
+
+impl GameStatus {
+    pub fn is_over(&self) -> bool {
+        !matches!(self, GameStatus::Ongoing | GameStatus::Repetition | GameStatus::FiftyMove)
+    }
+
+    pub fn is_draw(&self) -> bool {
+        !matches!(self, GameStatus::Ongoing | GameStatus::Checkmate(_))
+    }
+
+    pub fn is_claimable(&self) -> bool {
+        matches!(self, GameStatus::Repetition | GameStatus::FiftyMove)
+    }
+}
+
+impl fmt::Display for GameStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+fn is_insufficient_material(board: &Bitboards) -> bool {
+    let pieces = |role: Role| board.get_pieces(Color::White, role) | board.get_pieces(Color::Black, role);
+
+    if pieces(Role::Pawn) | pieces(Role::Rook) | pieces(Role::Queen) != 0 {
+        return false;
+    }
+
+    let (knights, bichops) = (pieces(Role::Knight), pieces(Role::Bichop));
+
+    // A LONE MINOR PIECE, OR BICHOPS THAT ALL SHARE ONE SQUARE COLOR
+    (knights | bichops).count_ones() <= 1 || (knights == 0 && (bichops & LIGHT_SQUARES == 0 || bichops & !LIGHT_SQUARES == 0))
+}
+
+pub(crate) fn status(board: &Board) -> GameStatus {
+    // A MATE ON THE LAST MOVE STANDS EVEN WHEN A DRAW RULE WOULD APPLY
+    if !board.has_legal_moves() {
+        return if board.is_check() { GameStatus::Checkmate(board.get_active_color().opposite()) } else { GameStatus::Stalemate };
+    }
+
+    if is_insufficient_material(board.get_bitboards()) {
+        return GameStatus::InsufficientMaterial;
+    }
+
+    let repetitions = board.count_repetitions();
+    let halfmove_clock = board.get_halfmove_clock();
+
+    if repetitions >= 5 {
+        GameStatus::FivefoldRepetition
+    } else if halfmove_clock >= 150 {
+        GameStatus::SeventyFiveMove
+    } else if repetitions >= 3 {
+        GameStatus::Repetition
+    } else if halfmove_clock >= 100 {
+        GameStatus::FiftyMove
+    } else {
+        GameStatus::Ongoing
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::board::Board;
+    use crate::color::Color;
+    use crate::comp::ChessSquare;
+    use crate::status::GameStatus;
+
+    #[test]
+    fn status() {
+        assert_eq!(Board::new().status(), GameStatus::Ongoing);
+        assert_eq!(Board::from_fen("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1").unwrap().status(), GameStatus::Stalemate);
+        assert_eq!(Board::from_fen("6Qk/5K2/8/8/8/8/8/8 b - - 0 1").unwrap().status(), GameStatus::Checkmate(Color::White));
+        assert_eq!(Board::from_fen("4k3/8/8/8/8/8/4P3/4K3 w - - 99 80").unwrap().status(), GameStatus::Ongoing);
+        assert_eq!(Board::from_fen("4k3/8/8/8/8/8/4P3/4K3 w - - 100 80").unwrap().status(), GameStatus::FiftyMove);
+        assert_eq!(Board::from_fen("4k3/8/8/8/8/8/4P3/4K3 w - - 150 80").unwrap().status(), GameStatus::SeventyFiveMove);
+
+        // MATE ON THE MOVE THAT HITS THE SEVENTY FIVE MOVE LIMIT STILL COUNTS
+        assert_eq!(Board::from_fen("6Qk/5K2/8/8/8/8/8/8 b - - 150 100").unwrap().status(), GameStatus::Checkmate(Color::White));
+    }
+
+    #[test]
+    fn status_material() {
+        let positions = [
+            ("4k3/8/8/8/8/8/8/4K3 w - - 0 1", true),
+            ("4k3/8/8/8/8/8/8/2B1K3 w - - 0 1", true),
+            ("4k3/8/8/8/8/8/8/1N2K3 w - - 0 1", true),
+            ("2b1k3/8/8/8/8/8/8/2B1K3 w - - 0 1", false),
+            ("3bk3/8/8/8/8/8/8/2B1K3 w - - 0 1", true),
+            ("4k3/8/8/8/8/8/8/1NN1K3 w - - 0 1", false),
+            ("4k3/8/8/8/8/8/8/1NB1K3 w - - 0 1", false),
+            ("4k3/8/8/8/8/8/4P3/4K3 w - - 0 1", false),
+        ];
+
+        for &(position, dead) in positions.iter() {
+            assert_eq!(Board::from_fen(position).unwrap().status() == GameStatus::InsufficientMaterial, dead, "{}", position);
+        }
+    }
+
+    #[test]
+    fn status_repetition() {
+        let mut board = Board::new();
+
+        let shuffle = [((6, 0), (5, 2)), ((6, 7), (5, 5)), ((5, 2), (6, 0)), ((5, 5), (6, 7))];
+        let mut statuses = Vec::new();
+
+        for _ in 0..4 {
+            for &(from, to) in shuffle.iter() {
+                let outcome = board.move_piece_to(ChessSquare::new(from.0, from.1), ChessSquare::new(to.0, to.1)).unwrap();
+                statuses.push(outcome.get_status());
+            }
+        }
+
+        // THE START POSITION RECURS AFTER EVERY FOURTH MOVE
+        assert_eq!(statuses[3], GameStatus::Ongoing);
+        assert_eq!(statuses[7], GameStatus::Repetition);
+        assert_eq!(statuses[11], GameStatus::Repetition);
+        assert_eq!(statuses[15], GameStatus::FivefoldRepetition);
+        assert!(statuses[7].is_claimable() && !statuses[7].is_over());
+        assert!(statuses[15].is_over() && statuses[15].is_draw());
+
+        // EN PASSENT RIGHTS MAKE A POSITION DIFFERENT
+        let mut board = Board::from_fen("4k3/8/8/8/3p4/8/4P3/4K2N w - - 0 1").unwrap();
+        let moves = [((4, 1), (4, 3)), ((4, 7), (3, 7)), ((7, 0), (6, 2)), ((3, 7), (4, 7)), ((6, 2), (7, 0)), ((4, 7), (3, 7)), ((7, 0), (6, 2)), ((3, 7), (4, 7)), ((6, 2), (7, 0))];
+
+        let mut status = GameStatus::Ongoing;
+
+        for &(from, to) in moves.iter() {
+            status = board.move_piece_to(ChessSquare::new(from.0, from.1), ChessSquare::new(to.0, to.1)).unwrap().get_status();
+        }
+
+        assert_eq!(status, GameStatus::Ongoing);
+    }
+}
@@ -0,0 +1,168 @@
+use std::fmt;
+
+use crate::bitboard;
+use crate::bitboard::Bitboards;
+use crate::board::Board;
+use crate::color::Color;
+use crate::comp::ChessSquare;
+use crate::role::Role;
+
+/*
+ * Public Declarations:
+ * - enum PositionProblem
+ */
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum PositionProblem {
+    SquareCount(usize),
+    KingCount(Color, u32),
+    PawnOnBackRank(ChessSquare),
+    TooManyPawns(Color, u32),
+    TooManyPromotedPieces(Color, u32),
+    InactiveColorInCheck,
+    TooManyCheckers(u32),
+    InvalidEnPassant(ChessSquare),
+    InvalidCastling(Color),
+}
+
+impl fmt::Display for PositionProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PositionProblem::SquareCount(count) => write!(f, "Board must have 64 squares, found {}.", count),
+            PositionProblem::KingCount(color, count) => write!(f, "{} must have exactly one king, found {}.", color, count),
+            PositionProblem::PawnOnBackRank(square) => write!(f, "Pawn on back rank square {}.", square),
+            PositionProblem::TooManyPawns(color, count) => write!(f, "{} has {} pawns, at most 8 are possible.", color, count),
+            PositionProblem::TooManyPromotedPieces(color, count) => write!(f, "{} has {} promoted pieces, more than its missing pawns.", color, count),
+            PositionProblem::InactiveColorInCheck => write!(f, "The side not to move is in check."),
+            PositionProblem::TooManyCheckers(count) => write!(f, "The king is checked by {} pieces, at most 2 are possible.", count),
+            PositionProblem::InvalidEnPassant(square) => write!(f, "No pawn can have passed over en passant square {}.", square),
+            PositionProblem::InvalidCastling(color) => write!(f, "{} has castling rights without its king and rook on their home squares.", color),
+        }
+    }
+}
+
+pub(crate) fn format_problems(problems: &[PositionProblem]) -> String {
+    problems.iter().map(|problem| format!("{}", problem)).collect::<Vec<String>>().join(" ")
+}
+
+fn checkers(board: &Board, color: Color, king: u8) -> u32 {
+    let bitboards = board.get_bitboards();
+    let occupied = bitboards.get_occupied();
+
+    bitboard::ROLES.iter().map(|&role| {
+        (role.get_attacks(king, color, occupied) & bitboards.get_pieces(color.opposite(), role)).count_ones()
+    }).sum()
+}
+
+pub(crate) fn castling_problems(board: &Bitboards) -> Vec<PositionProblem> {
+    let castling = board.get_castling_rights();
+
+    [(Color::White, 0), (Color::Black, 56)].iter().filter(|&&(color, home)| {
+        let at_home = |square: u8, role: Role| board.get_piece(home + square) == Some((color, role));
+
+        let kingside = castling.is_kingside_allowed(color) && !(at_home(4, Role::King) && at_home(7, Role::Rook));
+        let queenside = castling.is_queenside_allowed(color) && !(at_home(4, Role::King) && at_home(0, Role::Rook));
+
+        kingside || queenside
+    }).map(|&(color, _)| PositionProblem::InvalidCastling(color)).collect()
+}
+
+pub(crate) fn validate(board: &Board) -> Vec<PositionProblem> {
+    let bitboards = board.get_bitboards();
+    let active_color = board.get_active_color();
+
+    let mut problems = Vec::new();
+
+    for &color in [Color::White, Color::Black].iter() {
+        let count = |role: Role| bitboards.get_pieces(color, role).count_ones();
+
+        let kings = count(Role::King);
+
+        if kings != 1 {
+            problems.push(PositionProblem::KingCount(color, kings));
+        }
+
+        let pawns = count(Role::Pawn);
+
+        if pawns > 8 {
+            problems.push(PositionProblem::TooManyPawns(color, pawns));
+        }
+
+        // EVERY PIECE BEYOND THE STARTING SET MUST HAVE BEEN A PAWN
+        let promoted = count(Role::Queen).saturating_sub(1) + count(Role::Rook).saturating_sub(2) + count(Role::Bichop).saturating_sub(2) + count(Role::Knight).saturating_sub(2);
+
+        if promoted > 8u32.saturating_sub(pawns) {
+            problems.push(PositionProblem::TooManyPromotedPieces(color, promoted));
+        }
+    }
+
+    let pawns = bitboards.get_pieces(Color::White, Role::Pawn) | bitboards.get_pieces(Color::Black, Role::Pawn);
+
+    for square in bitboard::squares(pawns & 0xff00_0000_0000_00ff) {
+        problems.push(PositionProblem::PawnOnBackRank(ChessSquare::from_index(square)));
+    }
+
+    // CHECKS ONLY MAKE SENSE WITH ONE KING ON EACH SIDE
+    if let (Some(active_king), Some(inactive_king)) = (bitboards.get_king(active_color), bitboards.get_king(active_color.opposite())) {
+        if bitboards.get_pieces(active_color, Role::King).count_ones() == 1 && bitboards.get_pieces(active_color.opposite(), Role::King).count_ones() == 1 {
+            if checkers(board, active_color.opposite(), inactive_king) > 0 {
+                problems.push(PositionProblem::InactiveColorInCheck);
+            }
+
+            let count = checkers(board, active_color, active_king);
+
+            if count > 2 {
+                problems.push(PositionProblem::TooManyCheckers(count));
+            }
+        }
+    }
+
+    problems.extend(castling_problems(bitboards));
+
+    // THE PAWN THAT PASSED MUST STAND IN FRONT WITH BOTH SQUARES IT CROSSED EMPTY
+    if let Some(square) = bitboards.get_en_passent() {
+        let (pawn_square, origin_square) = match active_color {
+            Color::White => (square - 8, square + 8),
+            Color::Black => (square + 8, square - 8),
+        };
+
+        let pawn_in_front = bitboards.get_piece(pawn_square) == Some((active_color.opposite(), Role::Pawn));
+        let crossed_empty = bitboards.get_piece(square).is_none() && bitboards.get_piece(origin_square).is_none();
+
+        if !pawn_in_front || !crossed_empty {
+            problems.push(PositionProblem::InvalidEnPassant(ChessSquare::from_index(square)));
+        }
+    }
+
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::board::Board;
+    use crate::color::Color;
+    use crate::comp::ChessSquare;
+    use crate::validate::PositionProblem;
+
+    #[test]
+    fn validate() {
+        let positions = [
+            (crate::fen::STARTING_POSITION, vec![]),
+            ("8/8/8/8/8/8/8/4K3 w - - 0 1", vec![PositionProblem::KingCount(Color::Black, 0)]),
+            ("4k3/8/8/8/8/8/8/3KK3 w - - 0 1", vec![PositionProblem::KingCount(Color::White, 2)]),
+            ("P3k3/8/8/8/8/8/8/4K2p w - - 0 1", vec![PositionProblem::PawnOnBackRank(ChessSquare::new(7, 0)), PositionProblem::PawnOnBackRank(ChessSquare::new(0, 7))]),
+            ("4k3/8/8/8/8/8/8/4K2r b - - 0 1", vec![PositionProblem::InactiveColorInCheck]),
+            ("4k3/8/8/8/1b2r3/5n2/8/4K3 w - - 0 1", vec![PositionProblem::TooManyCheckers(3)]),
+            ("4k3/8/8/8/8/8/PPPPPPPP/QQQQKQQQ w - - 0 1", vec![PositionProblem::TooManyPromotedPieces(Color::White, 6)]),
+            ("4k3/8/8/8/8/8/8/QQQQKQQQ w - - 0 1", vec![]),
+            ("4k3/8/8/3p4/8/8/8/4K3 w - e6 0 1", vec![PositionProblem::InvalidEnPassant(ChessSquare::new(4, 5))]),
+            ("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1", vec![]),
+            ("r3k3/8/8/8/8/8/8/4K2R w KQkq - 0 1", vec![PositionProblem::InvalidCastling(Color::White), PositionProblem::InvalidCastling(Color::Black)]),
+            ("r3k3/8/8/8/8/8/8/4K2R w Kq - 0 1", vec![]),
+        ];
+
+        for (position, problems) in positions.iter() {
+            assert_eq!(Board::from_fen_relaxed(position).unwrap().validate(), *problems, "{}", position);
+        }
+    }
+}
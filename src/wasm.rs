@@ -0,0 +1,127 @@
+use wasm_bindgen::prelude::*;
+
+use crate::board::Board;
+use crate::color::Color;
+use crate::comp::ChessSquare;
+use crate::comp::Move;
+use crate::fen;
+use crate::status::GameStatus;
+
+/*
+ * JavaScript classes, only built with the wasm feature:
+ * - Board
+ *
+ * Squares are names like "e4", moves are UCI strings like "e7e8q"
+ * and pieces are FEN letters, uppercase for white.
+ */
+
+fn js_error<E: std::fmt::Display>(err: E) -> JsError {
+    JsError::new(&format!("{}", err))
+}
+
+fn parse_square(name: &str) -> Result<ChessSquare, JsError> {
+    name.parse().map_err(js_error)
+}
+
+#[wasm_bindgen(js_name = Board)]
+pub struct WasmBoard {
+    board: Board,
+}
+
+#[wasm_bindgen(js_class = Board)]
+impl WasmBoard {
+    // STARTING POSITION WHEN NO FEN IS GIVEN
+    #[wasm_bindgen(constructor)]
+    pub fn new(fen: Option<String>) -> Result<WasmBoard, JsError> {
+        let board = match fen {
+            Some(fen) => Board::from_fen(&fen).map_err(js_error)?,
+            None => Board::new(),
+        };
+
+        Ok(WasmBoard { board })
+    }
+
+    pub fn fen(&self) -> String {
+        self.board.to_fen()
+    }
+
+    // "w" OR "b" LIKE IN FEN
+    pub fn turn(&self) -> String {
+        match self.board.get_active_color() {
+            Color::White => "w".to_string(),
+            Color::Black => "b".to_string(),
+        }
+    }
+
+    #[wasm_bindgen(js_name = pieceAt)]
+    pub fn piece_at(&self, square: &str) -> Result<Option<String>, JsError> {
+        let square = parse_square(square)?;
+
+        Ok(self.board.get_active_pieces()[square.get_index() as usize].map(|piece| fen::piece_to_char(piece).to_string()))
+    }
+
+    // 64 ENTRIES FROM a1 TO h8, EMPTY SQUARES ARE EMPTY STRINGS
+    pub fn pieces(&self) -> Vec<String> {
+        self.board.get_active_pieces().into_iter()
+            .map(|piece| piece.map_or(String::new(), |piece| fen::piece_to_char(piece).to_string()))
+            .collect()
+    }
+
+    #[wasm_bindgen(js_name = legalMoves)]
+    pub fn legal_moves(&self) -> Vec<String> {
+        self.board.legal_moves().into_iter().map(|chess_move| chess_move.to_uci()).collect()
+    }
+
+    #[wasm_bindgen(js_name = movesFrom)]
+    pub fn moves_from(&self, square: &str) -> Result<Vec<String>, JsError> {
+        let square = parse_square(square)?;
+
+        Ok(self.board.legal_moves().into_iter()
+            .filter(|chess_move| chess_move.get_from() == square)
+            .map(|chess_move| chess_move.to_uci())
+            .collect())
+    }
+
+    // PLAYS A UCI MOVE AND RETURNS ITS SAN
+    #[wasm_bindgen(js_name = move)]
+    pub fn play_uci(&mut self, uci: &str) -> Result<String, JsError> {
+        let (from, to, promotion) = self.board.parse_uci(uci).map_err(js_error)?;
+
+        self.board.play(Move::new(from, to, promotion)).map(|outcome| outcome.get_san().to_string()).map_err(js_error)
+    }
+
+    // PLAYS A SAN MOVE AND RETURNS ITS UCI
+    #[wasm_bindgen(js_name = moveSan)]
+    pub fn play_san(&mut self, san: &str) -> Result<String, JsError> {
+        let (from, to, promotion) = self.board.parse_san(san).map_err(js_error)?;
+        let chess_move = Move::new(from, to, promotion);
+
+        self.board.play(chess_move).map_err(js_error)?;
+
+        Ok(chess_move.to_uci())
+    }
+
+    #[wasm_bindgen(js_name = isCheck)]
+    pub fn is_check(&self) -> bool {
+        self.board.is_check()
+    }
+
+    #[wasm_bindgen(js_name = isCheckmate)]
+    pub fn is_checkmate(&self) -> bool {
+        matches!(self.board.status(), GameStatus::Checkmate(_))
+    }
+
+    #[wasm_bindgen(js_name = isGameOver)]
+    pub fn is_game_over(&self) -> bool {
+        self.board.status().is_over()
+    }
+
+    pub fn status(&self) -> String {
+        format!("{}", self.board.status())
+    }
+
+    // A NUMBER RATHER THAN A BIGINT, EXACT UP TO 2^53 NODES
+    pub fn perft(&self, depth: u32) -> f64 {
+        self.board.perft(depth) as f64
+    }
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "capi")]
+
+use std::env;
+use std::path::PathBuf;
+use std::process::Command;
+
+// BUILDS c/tests/test_chess_engine.c AGAINST THE CDYLIB AND THE GENERATED HEADER
+#[test]
+fn c_program() {
+    let manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let out = PathBuf::from(env!("CARGO_TARGET_TMPDIR"));
+
+    // CARGO TEST ONLY REFRESHES THE CDYLIB NEXT TO THE TEST BINARY IN target/<profile>/deps
+    let library = env::current_exe().unwrap().parent().unwrap().to_path_buf();
+    let program = out.join("test_chess_engine");
+    let compiler = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+
+    let status = Command::new(compiler)
+        .arg(manifest.join("c").join("tests").join("test_chess_engine.c"))
+        .arg("-I").arg(manifest.join("include"))
+        .arg("-L").arg(&library)
+        .arg("-lengine")
+        .arg("-o").arg(&program)
+        .status()
+        .expect("A C compiler is needed for the C API tests.");
+
+    assert!(status.success());
+
+    let output = Command::new(&program).env("LD_LIBRARY_PATH", &library).output().unwrap();
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "ok\n");
+}
@@ -0,0 +1,84 @@
+#![cfg(feature = "wasm")]
+
+// RUNS NATIVELY WITH cargo test AND HEADLESS UNDER NODE WITH wasm-pack test --node,
+// SO THE MOVE GENERATOR GIVES THE SAME COUNTS ON BOTH TARGETS
+
+use engine::wasm::WasmBoard;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const KIWIPETE: &str = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn perft() {
+    // SAME POSITIONS AND COUNTS AS THE NATIVE perft TEST IN board.rs
+    let positions = [
+        (None, 4, 197281.0),
+        (Some(KIWIPETE), 3, 97862.0),
+        (Some("8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1"), 4, 43238.0),
+        (Some("r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1"), 3, 9467.0),
+        (Some("rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8"), 3, 62379.0),
+        (Some("r4rk1/1pp1qppp/p1np1n2/2b1p1B1/2B1P1b1/P1NP1N2/1PP1QPPP/R4RK1 w - - 0 10"), 3, 89890.0),
+    ];
+
+    for &(position, depth, nodes) in positions.iter() {
+        let board = WasmBoard::new(position.map(String::from)).ok().unwrap();
+
+        assert_eq!(board.perft(depth), nodes);
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn moves() {
+    let mut board = WasmBoard::new(None).ok().unwrap();
+
+    assert_eq!(board.turn(), "w");
+    assert_eq!(board.legal_moves().len(), 20);
+    assert_eq!(board.moves_from("g1").ok().unwrap(), ["g1f3", "g1h3"]);
+    assert!(board.moves_from("e5").ok().unwrap().is_empty());
+
+    assert_eq!(board.play_uci("e2e4").ok().unwrap(), "e4");
+    assert_eq!(board.play_san("e5").ok().unwrap(), "e7e5");
+    assert_eq!(board.fen(), "rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq e6 0 2");
+
+    for san in ["Bc4", "Nc6", "Qh5", "Nf6"].iter() {
+        board.play_san(san).ok().unwrap();
+    }
+
+    assert_eq!(board.play_uci("h5f7").ok().unwrap(), "Qxf7#");
+    assert!(board.is_check() && board.is_checkmate() && board.is_game_over());
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn pieces() {
+    let board = WasmBoard::new(Some(KIWIPETE.to_string())).ok().unwrap();
+    let pieces = board.pieces();
+
+    assert_eq!(pieces.len(), 64);
+    assert_eq!(pieces[4], "K");
+    assert_eq!(pieces[63], "r");
+    assert_eq!(pieces[20], "");
+    assert_eq!(board.piece_at("e5").ok().unwrap(), Some("N".to_string()));
+    assert_eq!(board.piece_at("e3").ok().unwrap(), None);
+
+    // PROMOTIONS ARE WRITTEN WITH A LOWERCASE LETTER
+    let board = WasmBoard::new(Some("4k3/P7/8/8/8/8/8/4K3 w - - 0 1".to_string())).ok().unwrap();
+
+    assert_eq!(board.moves_from("a7").ok().unwrap(), ["a7a8q", "a7a8n", "a7a8r", "a7a8b"]);
+}
+
+// ERRORS BECOME JS EXCEPTIONS, WHICH ONLY EXIST ON WASM
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen_test]
+fn errors() {
+    let mut board = WasmBoard::new(None).ok().unwrap();
+
+    assert!(WasmBoard::new(Some("8/8/8/8/8/8/8/8 w - - 0 1".to_string())).is_err());
+    assert!(board.piece_at("i9").is_err());
+    assert!(board.play_uci("e2e5").is_err());
+    assert!(board.play_san("Ke2").is_err());
+}